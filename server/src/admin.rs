@@ -0,0 +1,264 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, Timestamp};
+use log::info;
+
+use crate::{
+    character::{find_character_by_name, Character},
+    chat::Mute,
+    combat::{after, is_before},
+    death::kill_entity,
+    lifecycle::despawn_player,
+    movement::teleport_entity,
+    EntityComponent, PlayerComponent, PlayerStances, StdbVector3,
+};
+
+const MAX_BAN_REASON_LEN: usize = 256;
+// A week, longer mutes are better served by a ban.
+const MAX_MUTE_MINUTES: u64 = 7 * 24 * 60;
+// How long a kicked player has to wait before entering the world again.
+const KICK_COOLDOWN_MICROS: u64 = 60_000_000;
+
+// Identities allowed to call the reducers below and the ones in config.rs.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct Admin {
+    #[primarykey]
+    pub identity: Identity,
+    pub granted: Timestamp,
+}
+
+// Banned identities can connect but not enter the world, see `create_player`.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct Ban {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub username: String,
+    pub reason: String,
+    pub banned_by: Identity,
+    pub banned_at: Timestamp,
+}
+
+// Kicked identities can't enter the world again until `until`, see `create_player`.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct Kick {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub until: Timestamp,
+}
+
+pub fn init_admins(owner_id: Identity, now: Timestamp) {
+    if Admin::filter_by_identity(&owner_id).is_none() {
+        Admin::insert(Admin {
+            identity: owner_id,
+            granted: now,
+        })
+        .expect("Failed to insert admin");
+    }
+}
+
+pub fn require_admin(identity: &Identity) -> Result<(), String> {
+    if Admin::filter_by_identity(identity).is_some() {
+        Ok(())
+    } else {
+        Err("Only admins can do that".to_string())
+    }
+}
+
+pub fn is_banned(identity: &Identity) -> bool {
+    Ban::filter_by_owner_id(identity).is_some()
+}
+
+pub fn is_kicked(identity: &Identity, now: Timestamp) -> bool {
+    Kick::filter_by_owner_id(identity).is_some_and(|kick| is_before(now, kick.until))
+}
+
+// Drops kicks whose cooldown is over. Run from `world_tick`.
+pub fn expire_kicks(now: Timestamp) {
+    let expired: Vec<Identity> = Kick::iter()
+        .filter(|kick| !is_before(now, kick.until))
+        .map(|kick| kick.owner_id)
+        .collect();
+    for owner_id in expired {
+        Kick::delete_by_owner_id(&owner_id);
+    }
+}
+
+fn find_target(name: &str) -> Result<Character, String> {
+    find_character_by_name(name).ok_or_else(|| format!("No player named {}", name.trim()))
+}
+
+// The target's entity, for commands that only make sense while they're in the world.
+fn find_target_entity(name: &str) -> Result<(PlayerComponent, EntityComponent), String> {
+    let character = find_target(name)?;
+    PlayerComponent::filter_by_owner_id(&character.owner_id)
+        .and_then(|player| EntityComponent::filter_by_entity_id(&player.entity_id).map(|entity| (player, entity)))
+        .ok_or_else(|| format!("{} is not in the world", character.username))
+}
+
+#[spacetimedb(reducer)]
+pub fn grant_admin(ctx: ReducerContext, name: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let target = find_target(&name)?;
+    if Admin::filter_by_identity(&target.owner_id).is_some() {
+        return Err(format!("{} is already an admin", target.username));
+    }
+    Admin::insert(Admin {
+        identity: target.owner_id,
+        granted: ctx.timestamp,
+    })
+    .expect("Failed to insert admin");
+    info!("{} was made an admin", target.username);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn revoke_admin(ctx: ReducerContext, name: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let target = find_target(&name)?;
+    if Admin::filter_by_identity(&target.owner_id).is_none() {
+        return Err(format!("{} is not an admin", target.username));
+    }
+    if Admin::iter().count() == 1 {
+        return Err("Can't remove the last admin".to_string());
+    }
+    Admin::delete_by_identity(&target.owner_id);
+    info!("{} is no longer an admin", target.username);
+    Ok(())
+}
+
+// Takes the player out of the world. Modules can't close connections, so the
+// client sees its player removed and says why, see character_creation/mod.rs.
+#[spacetimedb(reducer)]
+pub fn kick_player(ctx: ReducerContext, name: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let (player, _) = find_target_entity(&name)?;
+    despawn_player(&player);
+    Kick::delete_by_owner_id(&player.owner_id);
+    Kick::insert(Kick {
+        owner_id: player.owner_id,
+        until: after(ctx.timestamp, KICK_COOLDOWN_MICROS),
+    })
+    .expect("Failed to insert kick");
+    info!("{} was kicked", player.username);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn ban_player(ctx: ReducerContext, name: String, reason: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let target = find_target(&name)?;
+    if target.owner_id == ctx.sender {
+        return Err("You can't ban yourself".to_string());
+    }
+    if is_banned(&target.owner_id) {
+        return Err(format!("{} is already banned", target.username));
+    }
+    let reason = reason.trim();
+    if reason.chars().count() > MAX_BAN_REASON_LEN {
+        return Err(format!("Reasons are limited to {} characters", MAX_BAN_REASON_LEN));
+    }
+
+    Ban::insert(Ban {
+        owner_id: target.owner_id,
+        username: target.username.clone(),
+        reason: reason.to_string(),
+        banned_by: ctx.sender,
+        banned_at: ctx.timestamp,
+    })
+    .expect("Failed to insert ban");
+    // Banned admins lose their rights with it.
+    Admin::delete_by_identity(&target.owner_id);
+    if let Some(player) = PlayerComponent::filter_by_owner_id(&target.owner_id) {
+        despawn_player(&player);
+    }
+    info!("{} was banned: {}", target.username, reason);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn unban_player(ctx: ReducerContext, name: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let target = find_target(&name)?;
+    if !Ban::delete_by_owner_id(&target.owner_id) {
+        return Err(format!("{} is not banned", target.username));
+    }
+    info!("{} was unbanned", target.username);
+    Ok(())
+}
+
+// Muting someone who is already muted replaces the old duration.
+#[spacetimedb(reducer)]
+pub fn mute_player(ctx: ReducerContext, name: String, minutes: u64) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    if minutes == 0 || minutes > MAX_MUTE_MINUTES {
+        return Err(format!("Mutes last between 1 and {} minutes", MAX_MUTE_MINUTES));
+    }
+    let target = find_target(&name)?;
+    let mute = Mute {
+        owner_id: target.owner_id,
+        until: after(ctx.timestamp, minutes * 60_000_000),
+    };
+    if Mute::filter_by_owner_id(&target.owner_id).is_some() {
+        Mute::update_by_owner_id(&target.owner_id, mute);
+    } else {
+        Mute::insert(mute).expect("Failed to insert mute");
+    }
+    info!("{} was muted for {} minutes", target.username, minutes);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn unmute_player(ctx: ReducerContext, name: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let target = find_target(&name)?;
+    if !Mute::delete_by_owner_id(&target.owner_id) {
+        return Err(format!("{} is not muted", target.username));
+    }
+    info!("{} was unmuted", target.username);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn teleport_player(ctx: ReducerContext, name: String, position: StdbVector3) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    if !(position.x.is_finite() && position.y.is_finite() && position.z.is_finite()) {
+        return Err("Invalid position".to_string());
+    }
+    let (player, mut entity) = find_target_entity(&name)?;
+    teleport_entity(&mut entity, position, ctx.timestamp);
+    EntityComponent::update_by_entity_id(&player.entity_id, entity);
+    info!("{} was teleported", player.username);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn set_player_stance(ctx: ReducerContext, name: String, stance: PlayerStances) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let (player, mut entity) = find_target_entity(&name)?;
+    entity.stance = stance;
+    EntityComponent::update_by_entity_id(&player.entity_id, entity);
+    info!("{} was put in {:?} stance", player.username, stance);
+    Ok(())
+}
+
+// Clamped to the entity's `max_health`.
+#[spacetimedb(reducer)]
+pub fn set_player_health(ctx: ReducerContext, name: String, health: f32) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    if !health.is_finite() {
+        return Err("Invalid health".to_string());
+    }
+    let (player, mut entity) = find_target_entity(&name)?;
+    if entity.dead {
+        return Err(format!("{} is dead and has to respawn first", player.username));
+    }
+    entity.health = health.clamp(0.0, entity.max_health);
+    let health = entity.health;
+    if health <= 0.0 {
+        kill_entity(&mut entity, ctx.timestamp);
+    }
+    EntityComponent::update_by_entity_id(&player.entity_id, entity);
+    info!("{} was set to {} health", player.username, health);
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, SpacetimeType, Timestamp};
+use log::info;
+
+use crate::{death::Death, inventory::add_items, EntityComponent, PlayerStances, StdbVector3};
+
+const USERNAME_MIN_LEN: usize = 3;
+const USERNAME_MAX_LEN: usize = 16;
+// Names players can't take, compared case-insensitively.
+const RESERVED_USERNAMES: &[&str] = &["admin", "administrator", "moderator", "server", "system", "gm"];
+// Item ids and quantities every new character starts with, see `init_item_defs`.
+const STARTING_ITEMS: &[(u32, u32)] = &[(1, 3), (2, 2), (3, 2), (100, 1)];
+
+#[derive(SpacetimeType, Clone)]
+pub struct CharacterStats {
+    pub level: u32,
+    pub experience: u64,
+    pub strength: u32,
+    pub dexterity: u32,
+    pub vitality: u32,
+}
+
+impl CharacterStats {
+    pub fn max_health(&self) -> f32 {
+        50.0 + self.vitality as f32 * 5.0
+    }
+
+    pub fn max_stamina(&self) -> f32 {
+        50.0 + self.dexterity as f32 * 5.0
+    }
+
+    pub fn max_mana(&self) -> f32 {
+        40.0 + self.level as f32 * 10.0
+    }
+}
+
+impl Default for CharacterStats {
+    fn default() -> Self {
+        Self {
+            level: 1,
+            experience: 0,
+            strength: 10,
+            dexterity: 10,
+            vitality: 10,
+        }
+    }
+}
+
+// The persistent part of a player. Unlike `PlayerComponent` and `EntityComponent`
+// this row survives a disconnect, so the player resumes where they left off.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct Character {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub username: String,
+    // The entity currently representing this character in the world. Only
+    // meaningful while `online` is true.
+    pub entity_id: u64,
+    pub position: StdbVector3,
+    pub direction: f32,
+    pub stance: PlayerStances,
+    pub stats: CharacterStats,
+    pub online: bool,
+    // The entity's pools when it left the world, so leaving doesn't heal.
+    pub health: f32,
+    pub stamina: f32,
+    pub mana: f32,
+    // When the character died, if it left the world dead. It rejoins dead and
+    // the respawn timer carries on from there, see `create_player`.
+    pub died: Option<Timestamp>,
+}
+
+impl Character {
+    pub fn new(owner_id: Identity, username: String) -> Self {
+        let stats = CharacterStats::default();
+        Self {
+            owner_id,
+            username,
+            entity_id: 0,
+            position: StdbVector3::default(),
+            direction: 0.0,
+            stance: PlayerStances::default(),
+            health: stats.max_health(),
+            stamina: stats.max_stamina(),
+            mana: stats.max_mana(),
+            stats,
+            online: false,
+            died: None,
+        }
+    }
+
+    // Builds the entity the character is restored into when the player rejoins.
+    pub fn to_entity(&self) -> EntityComponent {
+        let mut entity = EntityComponent {
+            direction: self.direction,
+            stance: self.stance,
+            health: self.health.min(self.stats.max_health()),
+            max_health: self.stats.max_health(),
+            stamina: self.stamina.min(self.stats.max_stamina()),
+            max_stamina: self.stats.max_stamina(),
+            mana: self.mana.min(self.stats.max_mana()),
+            max_mana: self.stats.max_mana(),
+            ..Default::default()
+        };
+        if self.died.is_some() {
+            entity.health = 0.0;
+            entity.dead = true;
+        }
+        entity.set_position(self.position.clone());
+        entity
+    }
+}
+
+// Copies the live entity state back into the character so it can be restored later.
+pub fn save_character(owner_id: &Identity, entity: &EntityComponent) {
+    if let Some(mut character) = Character::filter_by_owner_id(owner_id) {
+        character.position = entity.position.clone();
+        character.direction = entity.direction;
+        character.stance = entity.stance;
+        character.health = entity.health;
+        character.stamina = entity.stamina;
+        character.mana = entity.mana;
+        character.died = if entity.dead {
+            Death::filter_by_entity_id(&entity.entity_id).map(|death| death.died)
+        } else {
+            None
+        };
+        Character::update_by_owner_id(owner_id, character);
+    }
+}
+
+// Names are unique regardless of case, see `validate_username`.
+pub fn find_character_by_name(name: &str) -> Option<Character> {
+    let name = name.trim();
+    Character::iter().find(|c| c.username.eq_ignore_ascii_case(name))
+}
+
+pub fn set_character_online(owner_id: &Identity, online: bool) {
+    if let Some(mut character) = Character::filter_by_owner_id(owner_id) {
+        character.online = online;
+        Character::update_by_owner_id(owner_id, character);
+    }
+}
+
+// Called from the character creation screen before the player enters the world.
+#[spacetimedb(reducer)]
+pub fn create_character(ctx: ReducerContext, username: String) -> Result<(), String> {
+    if Character::filter_by_owner_id(&ctx.sender).is_some() {
+        return Err("You already have a character".to_string());
+    }
+
+    let username = validate_username(&username)?;
+    Character::insert(Character::new(ctx.sender, username.clone()))
+        .expect("Failed to insert character.");
+    for (item_id, quantity) in STARTING_ITEMS {
+        add_items(ctx.sender, *item_id, *quantity);
+    }
+
+    info!("Character created: {}", username);
+    Ok(())
+}
+
+// Checks length, charset, reserved names and uniqueness and returns the trimmed name.
+pub fn validate_username(username: &str) -> Result<String, String> {
+    let username = username.trim();
+    let len = username.chars().count();
+
+    if len < USERNAME_MIN_LEN || len > USERNAME_MAX_LEN {
+        return Err(format!(
+            "Name must be between {} and {} characters",
+            USERNAME_MIN_LEN, USERNAME_MAX_LEN
+        ));
+    }
+    if !username.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err("Name must start with a letter".to_string());
+    }
+    if !username.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err("Name may only contain letters, digits, '_' and '-'".to_string());
+    }
+    if RESERVED_USERNAMES.iter().any(|r| r.eq_ignore_ascii_case(username)) {
+        return Err("That name is reserved".to_string());
+    }
+    if Character::iter().any(|c| c.username.eq_ignore_ascii_case(username)) {
+        return Err("That name is already taken".to_string());
+    }
+
+    Ok(username.to_string())
+}
@@ -0,0 +1,240 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, SpacetimeType, Timestamp};
+use log::info;
+
+use crate::{
+    character::{find_character_by_name, Character},
+    combat::{after, is_before},
+    EntityComponent, PlayerComponent,
+};
+
+const MAX_CHAT_LEN: usize = 256;
+// Each identity may send this many messages per window.
+const RATE_LIMIT_MESSAGES: u32 = 5;
+const RATE_LIMIT_WINDOW_MICROS: u64 = 10_000_000;
+// How far `Say` carries on the ground plane.
+const SAY_RANGE: f32 = 20.0;
+// `Zone` reaches everyone within this many grid cells of the sender, see `CELL_SIZE`.
+const ZONE_RADIUS_CELLS: i32 = 2;
+// Clients keep their own log, old rows only cost subscribers.
+const CHAT_RETENTION_MICROS: u64 = 300_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum ChatScope {
+    // Players within `SAY_RANGE` of the sender.
+    Say,
+    // Players within `ZONE_RADIUS_CELLS` cells of the sender.
+    Zone,
+    // Everyone.
+    Global,
+    // One player, by character name.
+    Whisper,
+}
+
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct ChatChannel {
+    #[primarykey]
+    pub channel_id: u32,
+    pub name: String,
+    pub scope: ChatScope,
+}
+
+// Written once per recipient, so each client can subscribe to just the rows
+// addressed to it and whispers stay between the two players.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct ChatMessage {
+    #[primarykey]
+    #[autoinc]
+    pub message_id: u64,
+    pub channel_id: u32,
+    pub sender_id: Identity,
+    pub sender_name: String,
+    pub recipient_id: Identity,
+    pub text: String,
+    pub sent: Timestamp,
+}
+
+// Messages sent by an identity in the current rate limit window.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct ChatRateLimit {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub window_start: Timestamp,
+    pub count: u32,
+}
+
+// Identities that may not send chat messages until `until`, see `mute_player`.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct Mute {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub until: Timestamp,
+}
+
+pub fn init_chat_channels() {
+    let channels = [
+        (1, "Say", ChatScope::Say),
+        (2, "Zone", ChatScope::Zone),
+        (3, "Global", ChatScope::Global),
+        (4, "Whisper", ChatScope::Whisper),
+    ];
+    for (channel_id, name, scope) in channels {
+        if ChatChannel::filter_by_channel_id(&channel_id).is_none() {
+            ChatChannel::insert(ChatChannel {
+                channel_id,
+                name: name.to_string(),
+                scope,
+            })
+            .expect("Failed to insert chat channel");
+        }
+    }
+}
+
+// Sends `text` to everyone the channel's scope reaches from the sender.
+// `whisper_to` is the character name for whisper channels and ignored otherwise.
+#[spacetimedb(reducer)]
+pub fn send_chat(ctx: ReducerContext, channel_id: u32, text: String, whisper_to: Option<String>) -> Result<(), String> {
+    let Some(channel) = ChatChannel::filter_by_channel_id(&channel_id) else {
+        return Err("Unknown channel".to_string());
+    };
+    let Some(sender) = Character::filter_by_owner_id(&ctx.sender) else {
+        return Err("Create a character first".to_string());
+    };
+    if Mute::filter_by_owner_id(&ctx.sender).is_some_and(|mute| is_before(ctx.timestamp, mute.until)) {
+        return Err("You are muted".to_string());
+    }
+    let text = validate_chat_text(&text)?;
+    check_rate_limit(ctx.sender, ctx.timestamp)?;
+
+    let recipients = match channel.scope {
+        ChatScope::Global => PlayerComponent::iter().map(|player| player.owner_id).collect(),
+        ChatScope::Whisper => {
+            let Some(name) = whisper_to.as_deref().map(str::trim).filter(|name| !name.is_empty()) else {
+                return Err("Whisper to whom?".to_string());
+            };
+            let Some(target) = find_character_by_name(name) else {
+                return Err(format!("No player named {}", name));
+            };
+            if !target.online {
+                return Err(format!("{} is offline", target.username));
+            }
+            // The sender gets a copy so it shows up in their own log.
+            if target.owner_id == ctx.sender {
+                vec![ctx.sender]
+            } else {
+                vec![ctx.sender, target.owner_id]
+            }
+        }
+        ChatScope::Say | ChatScope::Zone => {
+            let origin = PlayerComponent::filter_by_owner_id(&ctx.sender)
+                .and_then(|player| EntityComponent::filter_by_entity_id(&player.entity_id))
+                .ok_or_else(|| "You're not in the world".to_string())?;
+            PlayerComponent::iter()
+                .filter(|player| {
+                    EntityComponent::filter_by_entity_id(&player.entity_id)
+                        .is_some_and(|entity| in_scope(channel.scope, &origin, &entity))
+                })
+                .map(|player| player.owner_id)
+                .collect()
+        }
+    };
+
+    for recipient_id in recipients {
+        ChatMessage::insert(ChatMessage {
+            message_id: 0,
+            channel_id,
+            sender_id: ctx.sender,
+            sender_name: sender.username.clone(),
+            recipient_id,
+            text: text.clone(),
+            sent: ctx.timestamp,
+        })
+        .expect("Failed to insert chat message");
+    }
+
+    info!("[{}] {}: {}", channel.name, sender.username, text);
+    Ok(())
+}
+
+fn in_scope(scope: ChatScope, origin: &EntityComponent, entity: &EntityComponent) -> bool {
+    match scope {
+        ChatScope::Say => {
+            let dx = entity.position.x - origin.position.x;
+            let dz = entity.position.z - origin.position.z;
+            (dx * dx + dz * dz).sqrt() <= SAY_RANGE
+        }
+        ChatScope::Zone => {
+            (entity.cell_x - origin.cell_x).abs() <= ZONE_RADIUS_CELLS
+                && (entity.cell_z - origin.cell_z).abs() <= ZONE_RADIUS_CELLS
+        }
+        ChatScope::Global | ChatScope::Whisper => true,
+    }
+}
+
+// Trims the message and checks its length and characters.
+pub fn validate_chat_text(text: &str) -> Result<String, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Message is empty".to_string());
+    }
+    if text.chars().count() > MAX_CHAT_LEN {
+        return Err(format!("Messages are limited to {} characters", MAX_CHAT_LEN));
+    }
+    if text.chars().any(char::is_control) {
+        return Err("Message contains invalid characters".to_string());
+    }
+    Ok(text.to_string())
+}
+
+fn check_rate_limit(owner_id: Identity, now: Timestamp) -> Result<(), String> {
+    let mut limit = ChatRateLimit::filter_by_owner_id(&owner_id).unwrap_or(ChatRateLimit {
+        owner_id,
+        window_start: now,
+        count: 0,
+    });
+    if !is_before(now, after(limit.window_start, RATE_LIMIT_WINDOW_MICROS)) {
+        limit.window_start = now;
+        limit.count = 0;
+    }
+    if limit.count >= RATE_LIMIT_MESSAGES {
+        return Err("You're sending messages too quickly".to_string());
+    }
+    limit.count += 1;
+
+    if ChatRateLimit::filter_by_owner_id(&owner_id).is_some() {
+        ChatRateLimit::update_by_owner_id(&owner_id, limit);
+    } else {
+        ChatRateLimit::insert(limit).expect("Failed to insert chat rate limit");
+    }
+    Ok(())
+}
+
+// Drops old messages and rate limit windows and mutes that have run out.
+pub fn prune_chat(now: Timestamp) {
+    let old: Vec<u64> = ChatMessage::iter()
+        .filter(|m| !is_before(now, after(m.sent, CHAT_RETENTION_MICROS)))
+        .map(|m| m.message_id)
+        .collect();
+    for message_id in old {
+        ChatMessage::delete_by_message_id(&message_id);
+    }
+
+    let unmuted: Vec<Identity> = Mute::iter()
+        .filter(|m| !is_before(now, m.until))
+        .map(|m| m.owner_id)
+        .collect();
+    for owner_id in unmuted {
+        Mute::delete_by_owner_id(&owner_id);
+    }
+
+    let expired: Vec<Identity> = ChatRateLimit::iter()
+        .filter(|l| !is_before(now, after(l.window_start, RATE_LIMIT_WINDOW_MICROS)))
+        .map(|l| l.owner_id)
+        .collect();
+    for owner_id in expired {
+        ChatRateLimit::delete_by_owner_id(&owner_id);
+    }
+}
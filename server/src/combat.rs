@@ -0,0 +1,332 @@
+use spacetimedb::{spacetimedb, ReducerContext, SpacetimeType, Timestamp};
+use log::info;
+
+use crate::{character::Character, death::kill_entity, spells::is_casting, EntityComponent, PlayerAction, PlayerComponent, PlayerStances};
+
+const ATTACK_RANGE: f32 = 0.8;
+// Cosine of the half-angle of the cone in front of the attacker that can be hit.
+const ATTACK_ARC_COS: f32 = 0.5;
+const ATTACK_COOLDOWN_MICROS: u64 = 800_000;
+const ATTACK_STAMINA_COST: f32 = 10.0;
+const BASE_DAMAGE: f32 = 10.0;
+
+const BLOCK_WINDOW_MICROS: u64 = 800_000;
+const BLOCK_COOLDOWN_MICROS: u64 = 1_000_000;
+const BLOCK_STAMINA_COST: f32 = 5.0;
+const DODGE_IFRAME_MICROS: u64 = 400_000;
+const DODGE_COOLDOWN_MICROS: u64 = 1_200_000;
+const DODGE_STAMINA_COST: f32 = 15.0;
+
+// Clients only need events long enough to play the hit reaction.
+const COMBAT_EVENT_RETENTION_MICROS: u64 = 10_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum CombatEventKind {
+    Hit,
+    Blocked,
+    Dodged,
+    Killed,
+}
+
+// One row per resolved hit. Clients subscribe to it for hit reactions and damage numbers.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct CombatEvent {
+    #[primarykey]
+    #[autoinc]
+    pub event_id: u64,
+    pub attacker_id: u64,
+    pub target_id: u64,
+    pub damage: f32,
+    pub kind: CombatEventKind,
+    pub timestamp: Timestamp,
+}
+
+// When an entity is next allowed to attack, block or dodge.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct CombatCooldown {
+    #[primarykey]
+    pub entity_id: u64,
+    pub next_attack: Timestamp,
+    pub next_block: Timestamp,
+    pub next_dodge: Timestamp,
+}
+
+// Timed defensive windows opened by `block` and `dodge`, checked when damage is applied.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct DefenseWindow {
+    #[primarykey]
+    pub entity_id: u64,
+    pub block_until: Timestamp,
+    pub invulnerable_until: Timestamp,
+}
+
+// Swings at everything in range and in front of the caller's entity.
+#[spacetimedb(reducer)]
+pub fn attack(ctx: ReducerContext) -> Result<(), String> {
+    let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    let Some(mut attacker) = EntityComponent::filter_by_entity_id(&player.entity_id) else {
+        return Err("Player not found".to_string());
+    };
+
+    if attacker.health <= 0.0 {
+        return Err("Dead players can't attack".to_string());
+    }
+    if is_casting(&attacker) {
+        return Err("Can't attack while casting".to_string());
+    }
+    if attacker.stance == PlayerStances::NonCombat {
+        return Err("Not in a combat stance".to_string());
+    }
+    let mut cooldown = cooldown_for(attacker.entity_id);
+    if is_before(ctx.timestamp, cooldown.next_attack) {
+        return Err("Attack is on cooldown".to_string());
+    }
+    if attacker.stamina < ATTACK_STAMINA_COST {
+        return Err("Not enough stamina".to_string());
+    }
+
+    attacker.stamina -= ATTACK_STAMINA_COST;
+    attacker.action = PlayerAction::Attack;
+    EntityComponent::update_by_entity_id(&attacker.entity_id, attacker.clone());
+    cooldown.next_attack = after(ctx.timestamp, ATTACK_COOLDOWN_MICROS);
+    save_cooldown(cooldown);
+
+    let damage = attack_power(&player) * attacker.stance.damage_dealt_multiplier();
+    for target in attacker.nearby() {
+        if target.entity_id == attacker.entity_id || target.health <= 0.0 {
+            continue;
+        }
+        if !in_attack_arc(&attacker, &target) {
+            continue;
+        }
+        apply_damage(&attacker, target, damage, ctx.timestamp);
+    }
+
+    Ok(())
+}
+
+// Raises the caller's guard. Hits from the front during the window are reduced
+// according to the blocker's stance.
+#[spacetimedb(reducer)]
+pub fn block(ctx: ReducerContext) -> Result<(), String> {
+    let mut entity = own_living_entity(&ctx)?;
+    if entity.stance == PlayerStances::NonCombat {
+        return Err("Not in a combat stance".to_string());
+    }
+
+    let mut cooldown = cooldown_for(entity.entity_id);
+    if is_before(ctx.timestamp, cooldown.next_block) {
+        return Err("Block is on cooldown".to_string());
+    }
+    if entity.stamina < BLOCK_STAMINA_COST {
+        return Err("Not enough stamina".to_string());
+    }
+
+    entity.stamina -= BLOCK_STAMINA_COST;
+    entity.action = PlayerAction::Block;
+    EntityComponent::update_by_entity_id(&entity.entity_id, entity.clone());
+    cooldown.next_block = after(ctx.timestamp, BLOCK_COOLDOWN_MICROS);
+    save_cooldown(cooldown);
+
+    let mut window = window_for(entity.entity_id);
+    window.block_until = after(ctx.timestamp, BLOCK_WINDOW_MICROS);
+    save_window(window);
+
+    Ok(())
+}
+
+// Opens a short invulnerability window during which hits are ignored.
+#[spacetimedb(reducer)]
+pub fn dodge(ctx: ReducerContext) -> Result<(), String> {
+    let mut entity = own_living_entity(&ctx)?;
+
+    let mut cooldown = cooldown_for(entity.entity_id);
+    if is_before(ctx.timestamp, cooldown.next_dodge) {
+        return Err("Dodge is on cooldown".to_string());
+    }
+    if entity.stamina < DODGE_STAMINA_COST {
+        return Err("Not enough stamina".to_string());
+    }
+
+    entity.stamina -= DODGE_STAMINA_COST;
+    entity.action = PlayerAction::Dodge;
+    EntityComponent::update_by_entity_id(&entity.entity_id, entity.clone());
+    cooldown.next_dodge = after(ctx.timestamp, DODGE_COOLDOWN_MICROS);
+    save_cooldown(cooldown);
+
+    let mut window = window_for(entity.entity_id);
+    window.invulnerable_until = after(ctx.timestamp, DODGE_IFRAME_MICROS);
+    save_window(window);
+
+    Ok(())
+}
+
+fn own_living_entity(ctx: &ReducerContext) -> Result<EntityComponent, String> {
+    let entity = PlayerComponent::filter_by_owner_id(&ctx.sender)
+        .and_then(|player| EntityComponent::filter_by_entity_id(&player.entity_id))
+        .ok_or_else(|| "Player not found".to_string())?;
+    if entity.health <= 0.0 {
+        return Err("Dead players can't do that".to_string());
+    }
+    if is_casting(&entity) {
+        return Err("Can't do that while casting".to_string());
+    }
+    Ok(entity)
+}
+
+// Range and facing check for an attacker swinging at a target.
+fn in_attack_arc(attacker: &EntityComponent, target: &EntityComponent) -> bool {
+    distance(attacker, target) <= ATTACK_RANGE && is_facing(attacker, target)
+}
+
+fn distance(a: &EntityComponent, b: &EntityComponent) -> f32 {
+    let dx = b.position.x - a.position.x;
+    let dz = b.position.z - a.position.z;
+    (dx * dx + dz * dz).sqrt()
+}
+
+// Whether `other` is inside the arc in front of `entity`. `direction` is the yaw the
+// client derives with atan2(-x, -z), so the facing vector on the ground plane is (-sin, -cos).
+fn is_facing(entity: &EntityComponent, other: &EntityComponent) -> bool {
+    let dx = other.position.x - entity.position.x;
+    let dz = other.position.z - entity.position.z;
+    let distance = (dx * dx + dz * dz).sqrt();
+    if distance <= f32::EPSILON {
+        return true;
+    }
+
+    let facing_x = -entity.direction.sin();
+    let facing_z = -entity.direction.cos();
+    (dx * facing_x + dz * facing_z) / distance >= ATTACK_ARC_COS
+}
+
+fn attack_power(player: &PlayerComponent) -> f32 {
+    let strength = Character::filter_by_owner_id(&player.owner_id)
+        .map(|c| c.stats.strength)
+        .unwrap_or(0);
+    BASE_DAMAGE + strength as f32 * 0.5
+}
+
+pub fn is_before(now: Timestamp, until: Timestamp) -> bool {
+    now.into_micros_since_epoch() < until.into_micros_since_epoch()
+}
+
+pub fn after(now: Timestamp, micros: u64) -> Timestamp {
+    Timestamp::from_micros_since_epoch(now.into_micros_since_epoch() + micros)
+}
+
+fn cooldown_for(entity_id: u64) -> CombatCooldown {
+    CombatCooldown::filter_by_entity_id(&entity_id).unwrap_or(CombatCooldown {
+        entity_id,
+        next_attack: Timestamp::from_micros_since_epoch(0),
+        next_block: Timestamp::from_micros_since_epoch(0),
+        next_dodge: Timestamp::from_micros_since_epoch(0),
+    })
+}
+
+fn save_cooldown(cooldown: CombatCooldown) {
+    if CombatCooldown::filter_by_entity_id(&cooldown.entity_id).is_some() {
+        let entity_id = cooldown.entity_id;
+        CombatCooldown::update_by_entity_id(&entity_id, cooldown);
+    } else {
+        CombatCooldown::insert(cooldown).expect("Failed to insert combat cooldown");
+    }
+}
+
+// Drops cooldowns and defense windows that have all run out, they act the same as no row.
+pub fn expire_combat_timers(now: Timestamp) {
+    let cooldowns: Vec<u64> = CombatCooldown::iter()
+        .filter(|c| !is_before(now, c.next_attack) && !is_before(now, c.next_block) && !is_before(now, c.next_dodge))
+        .map(|c| c.entity_id)
+        .collect();
+    for entity_id in cooldowns {
+        CombatCooldown::delete_by_entity_id(&entity_id);
+    }
+
+    let windows: Vec<u64> = DefenseWindow::iter()
+        .filter(|w| !is_before(now, w.block_until) && !is_before(now, w.invulnerable_until))
+        .map(|w| w.entity_id)
+        .collect();
+    for entity_id in windows {
+        DefenseWindow::delete_by_entity_id(&entity_id);
+    }
+}
+
+pub fn prune_combat_events(now: Timestamp) {
+    let old: Vec<u64> = CombatEvent::iter()
+        .filter(|e| !is_before(now, after(e.timestamp, COMBAT_EVENT_RETENTION_MICROS)))
+        .map(|e| e.event_id)
+        .collect();
+    for event_id in old {
+        CombatEvent::delete_by_event_id(&event_id);
+    }
+}
+
+fn window_for(entity_id: u64) -> DefenseWindow {
+    DefenseWindow::filter_by_entity_id(&entity_id).unwrap_or(DefenseWindow {
+        entity_id,
+        block_until: Timestamp::from_micros_since_epoch(0),
+        invulnerable_until: Timestamp::from_micros_since_epoch(0),
+    })
+}
+
+fn save_window(window: DefenseWindow) {
+    if DefenseWindow::filter_by_entity_id(&window.entity_id).is_some() {
+        let entity_id = window.entity_id;
+        DefenseWindow::update_by_entity_id(&entity_id, window);
+    } else {
+        DefenseWindow::insert(window).expect("Failed to insert defense window");
+    }
+}
+
+// Applies the target's stance modifier and any open block or dodge window,
+// writes the new health and records the event.
+pub fn apply_damage(attacker: &EntityComponent, mut target: EntityComponent, damage: f32, now: Timestamp) {
+    let attacker_id = attacker.entity_id;
+    let window = window_for(target.entity_id);
+
+    if is_before(now, window.invulnerable_until) {
+        record_event(attacker_id, target.entity_id, 0.0, CombatEventKind::Dodged, now);
+        return;
+    }
+
+    let mut damage = damage * target.stance.damage_taken_multiplier();
+    let blocked = is_before(now, window.block_until) && is_facing(&target, attacker);
+    if blocked {
+        damage *= target.stance.block_damage_multiplier();
+    }
+    target.health = (target.health - damage).max(0.0);
+
+    let kind = if target.health <= 0.0 {
+        CombatEventKind::Killed
+    } else if blocked {
+        CombatEventKind::Blocked
+    } else {
+        CombatEventKind::Hit
+    };
+    info!("Entity {} hit {} for {} ({:?})", attacker_id, target.entity_id, damage, kind);
+
+    record_event(attacker_id, target.entity_id, damage, kind, now);
+    if kind == CombatEventKind::Killed {
+        kill_entity(&mut target, now);
+    }
+    EntityComponent::update_by_entity_id(&target.entity_id, target);
+}
+
+fn record_event(attacker_id: u64, target_id: u64, damage: f32, kind: CombatEventKind, now: Timestamp) {
+
+    CombatEvent::insert(CombatEvent {
+        event_id: 0,
+        attacker_id,
+        target_id,
+        damage,
+        kind,
+        timestamp: now,
+    })
+    .expect("Failed to insert combat event");
+}
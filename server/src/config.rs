@@ -0,0 +1,96 @@
+use spacetimedb::{spacetimedb, ReducerContext};
+use log::info;
+
+use crate::admin::require_admin;
+
+// Bounds for the tunables below, so a typo can't stall the tick loop or the server.
+const MIN_TICK_MICROS: u64 = 20_000;
+const MAX_TICK_MICROS: u64 = 1_000_000;
+const MAX_MOTD_LEN: usize = 512;
+
+// Server-wide tunables, also read by clients. We're using this table as a
+// singleton, so there should typically only be one element where the version is 0.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct Config {
+    #[primarykey]
+    pub version: u32,
+    // Shown to players when they connect.
+    pub message_of_the_day: String,
+    // `create_player` turns players away once this many are in the world.
+    pub max_players: u32,
+    // Horizontal speed in units per second. Clients walk at this speed and
+    // `validate_move` checks against it.
+    pub max_speed: f32,
+    // Time between two `world_tick` calls.
+    pub tick_micros: u64,
+}
+
+pub fn init_config() {
+    if Config::filter_by_version(&0).is_none() {
+        Config::insert(Config {
+            version: 0,
+            message_of_the_day: "Welcome to Dagalok!".to_string(),
+            max_players: 64,
+            max_speed: 2.0,
+            tick_micros: 100_000,
+        })
+        .expect("Failed to insert config");
+    }
+}
+
+pub fn config() -> Config {
+    Config::filter_by_version(&0).expect("Config not initialized")
+}
+
+#[spacetimedb(reducer)]
+pub fn set_message_of_the_day(ctx: ReducerContext, message: String) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let message = message.trim().to_string();
+    if message.chars().count() > MAX_MOTD_LEN {
+        return Err(format!("The message of the day is limited to {} characters", MAX_MOTD_LEN));
+    }
+    let mut config = config();
+    config.message_of_the_day = message;
+    Config::update_by_version(&0, config);
+    info!("Message of the day changed");
+    Ok(())
+}
+
+// Players already in the world stay if the limit drops below their count.
+#[spacetimedb(reducer)]
+pub fn set_max_players(ctx: ReducerContext, max_players: u32) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    let mut config = config();
+    config.max_players = max_players;
+    Config::update_by_version(&0, config);
+    info!("Max players set to {}", max_players);
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn set_max_speed(ctx: ReducerContext, max_speed: f32) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    if !max_speed.is_finite() || max_speed <= 0.0 {
+        return Err("Max speed must be positive".to_string());
+    }
+    let mut config = config();
+    config.max_speed = max_speed;
+    Config::update_by_version(&0, config);
+    info!("Max speed set to {}", max_speed);
+    Ok(())
+}
+
+// Takes effect from the tick after next, the one already scheduled keeps its delay.
+#[spacetimedb(reducer)]
+pub fn set_tick_micros(ctx: ReducerContext, tick_micros: u64) -> Result<(), String> {
+    require_admin(&ctx.sender)?;
+    if !(MIN_TICK_MICROS..=MAX_TICK_MICROS).contains(&tick_micros) {
+        return Err(format!("Tick length must be between {} and {} microseconds", MIN_TICK_MICROS, MAX_TICK_MICROS));
+    }
+    let mut config = config();
+    config.tick_micros = tick_micros;
+    Config::update_by_version(&0, config);
+    info!("Tick length set to {} microseconds", tick_micros);
+    Ok(())
+}
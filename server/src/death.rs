@@ -0,0 +1,123 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, Timestamp};
+use log::info;
+
+use crate::{
+    combat::{after, is_before},
+    movement::teleport_entity,
+    status::clear_status_effects,
+    EntityComponent, PlayerAction, PlayerComponent, StdbVector3,
+};
+
+// How long a dead player lies there before `respawn` is allowed.
+const RESPAWN_DELAY_MICROS: u64 = 5_000_000;
+
+// Where dead players come back, see `respawn`.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct SpawnPoint {
+    #[primarykey]
+    pub spawn_point_id: u32,
+    pub name: String,
+    pub position: StdbVector3,
+}
+
+// One row per dead player. Clients show the death screen while their entity is
+// dead and enable the respawn button at `respawn_at`.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct Death {
+    #[primarykey]
+    pub entity_id: u64,
+    pub owner_id: Identity,
+    pub died: Timestamp,
+    pub respawn_at: Timestamp,
+}
+
+pub fn init_spawn_points() {
+    let points = [
+        (1, "Town", StdbVector3 { x: 0.0, y: 0.245, z: 0.0 }),
+        (2, "Graveyard", StdbVector3 { x: -20.0, y: 0.245, z: -20.0 }),
+    ];
+    for (spawn_point_id, name, position) in points {
+        if SpawnPoint::filter_by_spawn_point_id(&spawn_point_id).is_none() {
+            SpawnPoint::insert(SpawnPoint {
+                spawn_point_id,
+                name: name.to_string(),
+                position,
+            })
+            .expect("Failed to insert spawn point");
+        }
+    }
+}
+
+// Puts an entity into the dead state. The caller writes the entity back. Players
+// also get a `Death` row with their respawn timer, NPCs are handled in npc.rs.
+pub fn kill_entity(entity: &mut EntityComponent, now: Timestamp) {
+    if entity.dead {
+        return;
+    }
+    entity.health = 0.0;
+    entity.dead = true;
+    entity.moving = false;
+    entity.action = PlayerAction::None;
+
+    let Some(player) = PlayerComponent::filter_by_entity_id(&entity.entity_id) else {
+        return;
+    };
+    insert_death(entity.entity_id, player.owner_id, now);
+    info!("{} died", player.username);
+}
+
+// Starts the respawn timer of a player that died at `died`. Also used when a
+// character that logged out dead rejoins, so the wait is counted from the death.
+pub fn insert_death(entity_id: u64, owner_id: Identity, died: Timestamp) {
+    Death::delete_by_entity_id(&entity_id);
+    Death::insert(Death {
+        entity_id,
+        owner_id,
+        died,
+        respawn_at: after(died, RESPAWN_DELAY_MICROS),
+    })
+    .expect("Failed to insert death");
+}
+
+pub fn nearest_spawn_point(position: &StdbVector3) -> Option<SpawnPoint> {
+    let distance = |point: &SpawnPoint| {
+        let dx = point.position.x - position.x;
+        let dz = point.position.z - position.z;
+        dx * dx + dz * dz
+    };
+    SpawnPoint::iter().min_by(|a, b| distance(a).total_cmp(&distance(b)))
+}
+
+// Brings the caller's dead player back at the spawn point nearest to where they died.
+#[spacetimedb(reducer)]
+pub fn respawn(ctx: ReducerContext) -> Result<(), String> {
+    let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    let Some(mut entity) = EntityComponent::filter_by_entity_id(&player.entity_id) else {
+        return Err("Player not found".to_string());
+    };
+    if !entity.dead {
+        return Err("You're not dead".to_string());
+    }
+    if Death::filter_by_entity_id(&entity.entity_id).is_some_and(|death| is_before(ctx.timestamp, death.respawn_at)) {
+        return Err("You can't respawn yet".to_string());
+    }
+    let Some(point) = nearest_spawn_point(&entity.position) else {
+        return Err("There is nowhere to respawn".to_string());
+    };
+
+    teleport_entity(&mut entity, point.position, ctx.timestamp);
+    entity.dead = false;
+    entity.health = entity.max_health;
+    entity.stamina = entity.max_stamina;
+    entity.mana = entity.max_mana;
+    clear_status_effects(entity.entity_id);
+    Death::delete_by_entity_id(&entity.entity_id);
+    EntityComponent::update_by_entity_id(&player.entity_id, entity);
+
+    info!("{} respawned at {}", player.username, point.name);
+    Ok(())
+}
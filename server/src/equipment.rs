@@ -0,0 +1,108 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, SpacetimeType};
+
+use crate::inventory::{add_items, slot_of, InventorySlot, ItemDef, ItemKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum EquipSlot {
+    MainHand,
+    OffHand,
+    Armor,
+}
+
+impl EquipSlot {
+    fn accepts(&self, kind: ItemKind) -> bool {
+        match self {
+            EquipSlot::MainHand | EquipSlot::OffHand => kind == ItemKind::Weapon,
+            EquipSlot::Armor => kind == ItemKind::Armor,
+        }
+    }
+}
+
+// What a player is wearing. Public so every client can attach the right models.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct Equipment {
+    #[primarykey]
+    pub owner_id: Identity,
+    pub main_hand: Option<u32>,
+    pub off_hand: Option<u32>,
+    pub armor: Option<u32>,
+}
+
+impl Equipment {
+    fn new(owner_id: Identity) -> Self {
+        Self {
+            owner_id,
+            main_hand: None,
+            off_hand: None,
+            armor: None,
+        }
+    }
+
+    fn slot_mut(&mut self, slot: EquipSlot) -> &mut Option<u32> {
+        match slot {
+            EquipSlot::MainHand => &mut self.main_hand,
+            EquipSlot::OffHand => &mut self.off_hand,
+            EquipSlot::Armor => &mut self.armor,
+        }
+    }
+}
+
+fn save_equipment(equipment: Equipment) {
+    if Equipment::filter_by_owner_id(&equipment.owner_id).is_some() {
+        let owner_id = equipment.owner_id;
+        Equipment::update_by_owner_id(&owner_id, equipment);
+    } else {
+        Equipment::insert(equipment).expect("Failed to insert equipment");
+    }
+}
+
+// Equips the item in `inventory_slot`, putting whatever was equipped back into the inventory.
+#[spacetimedb(reducer)]
+pub fn equip_item(ctx: ReducerContext, inventory_slot: u32, equip_slot: EquipSlot) -> Result<(), String> {
+    let Some(mut stack) = slot_of(&ctx.sender, inventory_slot) else {
+        return Err("That slot is empty".to_string());
+    };
+    let Some(def) = ItemDef::filter_by_item_id(&stack.item_id) else {
+        return Err("Unknown item".to_string());
+    };
+    if !equip_slot.accepts(def.kind) {
+        return Err(format!("{} can't be equipped there", def.name));
+    }
+
+    if stack.quantity > 1 {
+        stack.quantity -= 1;
+        let slot_id = stack.slot_id;
+        InventorySlot::update_by_slot_id(&slot_id, stack);
+    } else {
+        InventorySlot::delete_by_slot_id(&stack.slot_id);
+    }
+
+    let mut equipment = Equipment::filter_by_owner_id(&ctx.sender).unwrap_or(Equipment::new(ctx.sender));
+    let previous = equipment.slot_mut(equip_slot).replace(def.item_id);
+    if let Some(previous) = previous {
+        if add_items(ctx.sender, previous, 1) > 0 {
+            return Err("Inventory is full".to_string());
+        }
+    }
+    save_equipment(equipment);
+
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn unequip_item(ctx: ReducerContext, equip_slot: EquipSlot) -> Result<(), String> {
+    let Some(mut equipment) = Equipment::filter_by_owner_id(&ctx.sender) else {
+        return Err("Nothing equipped".to_string());
+    };
+    let Some(item_id) = equipment.slot_mut(equip_slot).take() else {
+        return Err("Nothing equipped".to_string());
+    };
+
+    if add_items(ctx.sender, item_id, 1) > 0 {
+        return Err("Inventory is full".to_string());
+    }
+    save_equipment(equipment);
+
+    Ok(())
+}
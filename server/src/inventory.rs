@@ -0,0 +1,278 @@
+use spacetimedb::{spacetimedb, Identity, ReducerContext, SpacetimeType};
+use log::info;
+
+use crate::{spells::is_casting, EntityComponent, PlayerAction, PlayerComponent, StdbVector3};
+
+// The client shows the first five slots as the hotbar.
+pub const INVENTORY_SIZE: u32 = 20;
+const PICKUP_RANGE: f32 = 1.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum ItemKind {
+    Consumable,
+    Weapon,
+    Armor,
+    Misc,
+}
+
+#[derive(Clone, Debug, PartialEq, SpacetimeType)]
+pub enum ItemEffect {
+    None,
+    RestoreHealth(f32),
+    RestoreStamina(f32),
+    RestoreMana(f32),
+}
+
+// Static description of an item. `PlayerAction::UseItem` carries an `item_id` from here.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct ItemDef {
+    #[primarykey]
+    pub item_id: u32,
+    pub name: String,
+    pub description: String,
+    pub kind: ItemKind,
+    pub max_stack: u32,
+    pub effect: ItemEffect,
+}
+
+// A stack of items in a player's inventory. (owner_id, slot) is unique.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct InventorySlot {
+    #[primarykey]
+    #[autoinc]
+    pub slot_id: u64,
+    pub owner_id: Identity,
+    pub slot: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+// Items lying in the world that can be picked up.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct WorldItem {
+    #[primarykey]
+    #[autoinc]
+    pub world_item_id: u64,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub position: StdbVector3,
+}
+
+pub fn init_item_defs() {
+    let defs = [
+        (1, "Health Potion", "Restores 40 health.", ItemKind::Consumable, 10, ItemEffect::RestoreHealth(40.0)),
+        (2, "Stamina Tonic", "Restores 50 stamina.", ItemKind::Consumable, 10, ItemEffect::RestoreStamina(50.0)),
+        (3, "Mana Potion", "Restores 40 mana.", ItemKind::Consumable, 10, ItemEffect::RestoreMana(40.0)),
+        (100, "Axe", "A sturdy one-handed axe.", ItemKind::Weapon, 1, ItemEffect::None),
+        (200, "Leather Armor", "Light armor made of boiled leather.", ItemKind::Armor, 1, ItemEffect::None),
+    ];
+
+    for (item_id, name, description, kind, max_stack, effect) in defs {
+        if ItemDef::filter_by_item_id(&item_id).is_none() {
+            ItemDef::insert(ItemDef {
+                item_id,
+                name: name.to_string(),
+                description: description.to_string(),
+                kind,
+                max_stack,
+                effect,
+            })
+            .expect("Failed to insert item definition");
+        }
+    }
+}
+
+pub fn slot_of(owner_id: &Identity, slot: u32) -> Option<InventorySlot> {
+    InventorySlot::filter_by_owner_id(owner_id).find(|s| s.slot == slot)
+}
+
+fn own_slot(ctx: &ReducerContext, slot: u32) -> Result<InventorySlot, String> {
+    if slot >= INVENTORY_SIZE {
+        return Err("Invalid inventory slot".to_string());
+    }
+    slot_of(&ctx.sender, slot).ok_or_else(|| "That slot is empty".to_string())
+}
+
+fn own_entity(ctx: &ReducerContext) -> Result<EntityComponent, String> {
+    PlayerComponent::filter_by_owner_id(&ctx.sender)
+        .and_then(|player| EntityComponent::filter_by_entity_id(&player.entity_id))
+        .ok_or_else(|| "Player not found".to_string())
+}
+
+fn set_quantity(mut stack: InventorySlot, quantity: u32) {
+    if quantity == 0 {
+        InventorySlot::delete_by_slot_id(&stack.slot_id);
+    } else {
+        stack.quantity = quantity;
+        let slot_id = stack.slot_id;
+        InventorySlot::update_by_slot_id(&slot_id, stack);
+    }
+}
+
+// Adds items to the owner's inventory, topping up existing stacks before using
+// empty slots. Returns how many didn't fit.
+pub fn add_items(owner_id: Identity, item_id: u32, mut quantity: u32) -> u32 {
+    let Some(def) = ItemDef::filter_by_item_id(&item_id) else {
+        return quantity;
+    };
+
+    let stacks: Vec<InventorySlot> = InventorySlot::filter_by_owner_id(&owner_id)
+        .filter(|s| s.item_id == item_id)
+        .collect();
+    for stack in stacks {
+        let space = def.max_stack.saturating_sub(stack.quantity);
+        let added = space.min(quantity);
+        if added > 0 {
+            let total = stack.quantity + added;
+            set_quantity(stack, total);
+            quantity -= added;
+        }
+    }
+
+    for slot in 0..INVENTORY_SIZE {
+        if quantity == 0 {
+            break;
+        }
+        if slot_of(&owner_id, slot).is_none() {
+            let added = def.max_stack.min(quantity);
+            InventorySlot::insert(InventorySlot {
+                slot_id: 0,
+                owner_id,
+                slot,
+                item_id,
+                quantity: added,
+            })
+            .expect("Failed to insert inventory slot");
+            quantity -= added;
+        }
+    }
+
+    quantity
+}
+
+#[spacetimedb(reducer)]
+pub fn pickup_item(ctx: ReducerContext, world_item_id: u64) -> Result<(), String> {
+    let entity = own_entity(&ctx)?;
+    let Some(mut item) = WorldItem::filter_by_world_item_id(&world_item_id) else {
+        return Err("Item not found".to_string());
+    };
+
+    let dx = item.position.x - entity.position.x;
+    let dz = item.position.z - entity.position.z;
+    if (dx * dx + dz * dz).sqrt() > PICKUP_RANGE {
+        return Err("Too far away".to_string());
+    }
+
+    let left_over = add_items(ctx.sender, item.item_id, item.quantity);
+    if left_over == item.quantity {
+        return Err("Inventory is full".to_string());
+    }
+    if left_over == 0 {
+        WorldItem::delete_by_world_item_id(&world_item_id);
+    } else {
+        item.quantity = left_over;
+        WorldItem::update_by_world_item_id(&world_item_id, item);
+    }
+
+    Ok(())
+}
+
+// Drops the whole stack in `slot` at the player's feet.
+#[spacetimedb(reducer)]
+pub fn drop_item(ctx: ReducerContext, slot: u32) -> Result<(), String> {
+    let entity = own_entity(&ctx)?;
+    let stack = own_slot(&ctx, slot)?;
+
+    WorldItem::insert(WorldItem {
+        world_item_id: 0,
+        item_id: stack.item_id,
+        quantity: stack.quantity,
+        position: entity.position.clone(),
+    })
+    .expect("Failed to insert world item");
+    InventorySlot::delete_by_slot_id(&stack.slot_id);
+
+    Ok(())
+}
+
+// Moves a stack to another slot, merging same items and swapping different ones.
+#[spacetimedb(reducer)]
+pub fn move_item(ctx: ReducerContext, from_slot: u32, to_slot: u32) -> Result<(), String> {
+    if to_slot >= INVENTORY_SIZE {
+        return Err("Invalid inventory slot".to_string());
+    }
+    if from_slot == to_slot {
+        return Ok(());
+    }
+    let mut from = own_slot(&ctx, from_slot)?;
+
+    match slot_of(&ctx.sender, to_slot) {
+        None => {
+            from.slot = to_slot;
+            let slot_id = from.slot_id;
+            InventorySlot::update_by_slot_id(&slot_id, from);
+        }
+        Some(to) if to.item_id == from.item_id => {
+            let max_stack = ItemDef::filter_by_item_id(&from.item_id).map(|d| d.max_stack).unwrap_or(1);
+            let moved = max_stack.saturating_sub(to.quantity).min(from.quantity);
+            let to_quantity = to.quantity + moved;
+            let from_quantity = from.quantity - moved;
+            set_quantity(to, to_quantity);
+            set_quantity(from, from_quantity);
+        }
+        Some(mut to) => {
+            to.slot = from_slot;
+            from.slot = to_slot;
+            let (from_id, to_id) = (from.slot_id, to.slot_id);
+            InventorySlot::update_by_slot_id(&from_id, from);
+            InventorySlot::update_by_slot_id(&to_id, to);
+        }
+    }
+
+    Ok(())
+}
+
+// Uses one item from `slot`. Consumables apply their effect to the player's entity.
+#[spacetimedb(reducer)]
+pub fn use_item(ctx: ReducerContext, slot: u32) -> Result<(), String> {
+    let mut entity = own_entity(&ctx)?;
+    let stack = own_slot(&ctx, slot)?;
+    let Some(def) = ItemDef::filter_by_item_id(&stack.item_id) else {
+        return Err("Unknown item".to_string());
+    };
+
+    if def.kind != ItemKind::Consumable {
+        return Err(format!("{} can't be used", def.name));
+    }
+    if entity.health <= 0.0 {
+        return Err("Dead players can't do that".to_string());
+    }
+    if is_casting(&entity) {
+        return Err("Can't use items while casting".to_string());
+    }
+
+    match def.effect {
+        ItemEffect::RestoreHealth(amount) => {
+            entity.health = (entity.health + amount).min(entity.max_health);
+        }
+        ItemEffect::RestoreStamina(amount) => {
+            entity.stamina = (entity.stamina + amount).min(entity.max_stamina);
+        }
+        ItemEffect::RestoreMana(amount) => {
+            entity.mana = (entity.mana + amount).min(entity.max_mana);
+        }
+        ItemEffect::None => {}
+    }
+    entity.action = PlayerAction::UseItem(def.item_id);
+    let entity_id = entity.entity_id;
+    EntityComponent::update_by_entity_id(&entity_id, entity);
+
+    let quantity = stack.quantity - 1;
+    set_quantity(stack, quantity);
+    info!("{} used {}", ctx.sender, def.name);
+
+    Ok(())
+}
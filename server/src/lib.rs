@@ -1,16 +1,34 @@
+mod admin;
+mod character;
+mod chat;
+mod combat;
+mod config;
+mod death;
+mod equipment;
+mod inventory;
+mod lifecycle;
+mod movement;
+mod npc;
+mod spells;
+mod stances;
+mod status;
+mod world;
+
 use spacetimedb::{spacetimedb, Identity, SpacetimeType, ReducerContext};
 use log::info;
+use admin::{init_admins, is_banned, is_kicked};
+use character::{set_character_online, Character};
+use chat::init_chat_channels;
+use config::{config, init_config};
+use death::{init_spawn_points, insert_death};
+use inventory::init_item_defs;
+use lifecycle::despawn_player;
+use movement::{init_movement_config, validate_move};
+use npc::init_npcs;
+use spells::{init_spell_defs, is_casting};
+use world::init_world_clock;
 
 
-// We're using this table as a singleton, so there should typically only be one element where the version is 0.
-// #[spacetimedb(table)]
-// #[derive(Clone)]
-// pub struct Config {
-//     #[primarykey]
-//     pub version: u32,
-//     pub message_of_the_day: String,
-// }
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType, Default)]
 pub enum PlayerStances {
     #[default]
@@ -28,7 +46,8 @@ pub enum PlayerAction {
     Block,
     Dodge,
     UseItem(u32),
-    CastSpell(String),
+    // Set by `cast_spell` while the spell with this `SpellDef::spell_id` is being cast.
+    CastSpell(u32),
 }
 
 #[spacetimedb(table)]
@@ -39,18 +58,29 @@ pub struct Client {
     pub connected: bool,
 }
 
+// Side length of the grid cells clients subscribe to `EntityComponent` rows by.
+pub const CELL_SIZE: f32 = 16.0;
+
 // This allows us to store 3D points in tables.
-#[derive(SpacetimeType, Clone, Default)]
+#[derive(SpacetimeType, Clone, Debug, Default, PartialEq)]
 pub struct StdbVector3 {
     pub x: f32,
     pub y: f32,
     pub z: f32,
 }
 
+impl StdbVector3 {
+    // The grid cell this point falls in on the ground plane.
+    pub fn cell(&self) -> (i32, i32) {
+        ((self.x / CELL_SIZE).floor() as i32, (self.z / CELL_SIZE).floor() as i32)
+    }
+}
+
 // This stores information related to all entities in our game. In this tutorial
 // all entities must at least have an entity_id, a position, a direction and they
 // must specify whether or not they are moving.
 #[spacetimedb(table(public))]
+#[spacetimedb(index(btree, name = "cell", cell_x, cell_z))]
 #[derive(Clone, Default)]
 pub struct EntityComponent {
     #[primarykey]
@@ -64,6 +94,58 @@ pub struct EntityComponent {
     pub moving: bool,
     pub stance: PlayerStances,
     pub action: PlayerAction,
+    pub health: f32,
+    pub max_health: f32,
+    pub stamina: f32,
+    pub max_stamina: f32,
+    pub mana: f32,
+    pub max_mana: f32,
+    // Set once health reaches zero, see death.rs. Dead entities can't move or act
+    // until they `respawn`.
+    pub dead: bool,
+    // The last `update_player_position` sequence number applied to this entity.
+    // The owning client replays its newer inputs on top of `position`.
+    pub input_sequence: u32,
+    // The grid cell of `position`, kept in sync by `set_position`. Clients only
+    // subscribe to the cells around them.
+    pub cell_x: i32,
+    pub cell_z: i32,
+}
+
+impl EntityComponent {
+    pub fn set_position(&mut self, position: StdbVector3) {
+        (self.cell_x, self.cell_z) = position.cell();
+        self.position = position;
+    }
+
+    // Entities in this entity's cell and the eight around it, looked up through
+    // the `cell` index. Anything within `CELL_SIZE` of the entity is among them.
+    pub fn nearby(&self) -> Vec<EntityComponent> {
+        let (cell_x, cell_z) = (self.cell_x, self.cell_z);
+        (cell_x - 1..=cell_x + 1)
+            .flat_map(|x| EntityComponent::filter_by_cell_x(&x))
+            .filter(|entity| (entity.cell_z - cell_z).abs() <= 1)
+            .collect()
+    }
+}
+
+// Entities in every cell that overlaps the square of half-size `distance` around
+// `position`. Callers still check the exact distance.
+pub fn entities_around(position: &StdbVector3, distance: f32) -> Vec<EntityComponent> {
+    let corner = |offset: f32| {
+        StdbVector3 {
+            x: position.x + offset,
+            y: position.y,
+            z: position.z + offset,
+        }
+        .cell()
+    };
+    let (min_x, min_z) = corner(-distance);
+    let (max_x, max_z) = corner(distance);
+    (min_x..=max_x)
+        .flat_map(|x| EntityComponent::filter_by_cell_x(&x))
+        .filter(|entity| (min_z..=max_z).contains(&entity.cell_z))
+        .collect()
 }
 
 // All players have this component and it associates an entity with the user's
@@ -78,17 +160,27 @@ pub struct PlayerComponent {
     // The user's identity, which is unique to each player
     #[unique]
     pub owner_id: Identity,
-    //pub username: String,
+    pub username: String,
     //pub logged_in: bool,
 }
 
 #[spacetimedb(init)]
-pub fn init() {
-   
+pub fn init(ctx: ReducerContext) {
+    // Whoever published the module runs it.
+    init_admins(ctx.sender, ctx.timestamp);
+    // The tick loop reads its rate from the config, so it goes first.
+    init_config();
+    init_movement_config();
+    init_item_defs();
+    init_spell_defs();
+    init_chat_channels();
+    init_npcs();
+    init_spawn_points();
+    init_world_clock();
 }
 
-// This reducer is called when the user logs in for the first time and
-// enters a username
+// This reducer is called when the user logs in and puts their `Character` into the
+// world. The character has to be created first with `create_character`.
 #[spacetimedb(reducer)]
 pub fn create_player(ctx: ReducerContext) -> Result<(), String> {
     // Get the Identity of the client who called this reducer
@@ -101,15 +193,38 @@ pub fn create_player(ctx: ReducerContext) -> Result<(), String> {
         return Err("Player already exists".to_string());
     }
 
-    let entity_id = EntityComponent::insert(EntityComponent::default())
+    if is_banned(&owner_id) {
+        return Err("You are banned from this server".to_string());
+    }
+    if is_kicked(&owner_id, ctx.timestamp) {
+        return Err("You were kicked, try again in a minute".to_string());
+    }
+
+    let Some(mut character) = Character::filter_by_owner_id(&owner_id) else {
+        return Err("Create a character first".to_string());
+    };
+
+    if PlayerComponent::iter().count() >= config().max_players as usize {
+        return Err("The server is full".to_string());
+    }
+
+    let entity_id = EntityComponent::insert(character.to_entity())
         .expect("Failed to create a unique ObComponent.")
         .entity_id;
 
     PlayerComponent::insert(PlayerComponent {
         entity_id,
         owner_id,
+        username: character.username.clone(),
     })
     .expect("Failed to insert player component.");
+    if let Some(died) = character.died {
+        insert_death(entity_id, owner_id, died);
+    }
+
+    character.entity_id = entity_id;
+    character.online = true;
+    Character::update_by_owner_id(&owner_id, character);
 
     log::info!("Player created: {})", entity_id);
 
@@ -120,6 +235,10 @@ pub fn create_player(ctx: ReducerContext) -> Result<(), String> {
 // Called when the client connects, we update the logged_in state to true
 #[spacetimedb(connect)]
 pub fn client_connected(ctx: ReducerContext) {
+    // The connection can't be refused from here, `create_player` turns them away.
+    if is_banned(&ctx.sender) {
+        info!("Banned client connected: {}", ctx.sender);
+    }
     update_player_login_state(ctx, true);
 }
 
@@ -136,9 +255,11 @@ pub fn update_player_login_state(ctx: ReducerContext, connected: bool) {
             let mut client = client.clone();
             client.connected = connected;
             Client::update_by_client_id(&ctx.sender, client);
+            set_character_online(&ctx.sender, connected);
 
-                if !connected {
-                    remove_player(ctx).expect("player doesn't exist");
+                // Clients that never entered the world have no player to remove.
+                if !connected && PlayerComponent::filter_by_owner_id(&ctx.sender).is_some() {
+                    remove_player(ctx).expect("Failed to remove player");
                 }
                 info!("Updated client Login State");
         } else {
@@ -158,29 +279,34 @@ pub fn remove_player(ctx: ReducerContext) -> Result<(), String> {
     }
 
     if let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) {
-        let _player = player.clone();
-        PlayerComponent::delete_by_owner_id(&client_id);
-        log::info!("Removed Player: {}", _player.owner_id);
+        despawn_player(&player);
     }
 
     Ok(())
 }
 
 // Updates the position of a player. This is also called when the player stops moving.
+// The requested position is checked against the last accepted one, see movement.rs.
+// `sequence` increases with every call and is echoed back in `input_sequence`.
 #[spacetimedb(reducer)]
 pub fn update_player_position(
     ctx: ReducerContext,
     position: StdbVector3,
     direction: f32,
     moving: bool,
+    sequence: u32,
     //stance: PlayerStances,
 ) -> Result<(), String> {
     if let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) {
         if let Some(mut entity) = EntityComponent::filter_by_entity_id(&player.entity_id) {
-            entity.position = position;
-            entity.direction = direction;
-            entity.moving = moving;
-            //entity.stance = stance; // Update the stance
+            if entity.dead {
+                return Err("You are dead".to_string());
+            }
+            if sequence <= entity.input_sequence {
+                // Older than what we already applied.
+                return Ok(());
+            }
+            apply_move(&ctx, &mut entity, position, direction, moving, sequence);
             EntityComponent::update_by_entity_id(&player.entity_id, entity);
             return Ok(());
         }
@@ -189,19 +315,75 @@ pub fn update_player_position(
     Err("Player not found".to_string())
 }
 
+// Position and stance in one call, and clears the last action. This is what the client's send
+// scheduler uses, at a fixed rate and only when something changed.
 #[spacetimedb(reducer)]
-pub fn update_player_action(
+pub fn update_player_state(
     ctx: ReducerContext,
+    position: StdbVector3,
+    direction: f32,
+    moving: bool,
+    stance: PlayerStances,
     action: PlayerAction,
+    sequence: u32,
 ) -> Result<(), String> {
-    if let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) {
-        if let Some(mut entity) = EntityComponent::filter_by_entity_id(&player.entity_id) {
-            entity.action = action;
-            EntityComponent::update_by_entity_id(&player.entity_id, entity);
-            return Ok(());
-        }
+    // Actions go through their own reducers, which check cooldowns and costs.
+    // Clients can only clear the one they did last.
+    if action != PlayerAction::None {
+        return Err("Use attack, block, dodge, use_item or cast_spell for actions".to_string());
     }
-    return Err("Player not found".to_string())
+    let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    let Some(mut entity) = EntityComponent::filter_by_entity_id(&player.entity_id) else {
+        return Err("Player not found".to_string());
+    };
+    if entity.dead {
+        return Err("You are dead".to_string());
+    }
+    if sequence <= entity.input_sequence {
+        return Ok(());
+    }
+
+    // The stance goes first, the speed check depends on it.
+    entity.stance = stance;
+    apply_move(&ctx, &mut entity, position, direction, moving, sequence);
+    // Casts are tracked by the server, see spells.rs.
+    if !is_casting(&entity) {
+        entity.action = PlayerAction::None;
+    }
+    EntityComponent::update_by_entity_id(&player.entity_id, entity);
+
+    Ok(())
+}
+
+// Acknowledges `sequence` and moves the entity as far as `validate_move` allows.
+// A rejected move leaves the position alone, so the client snaps back to it.
+fn apply_move(
+    ctx: &ReducerContext,
+    entity: &mut EntityComponent,
+    position: StdbVector3,
+    direction: f32,
+    moving: bool,
+    sequence: u32,
+) {
+    entity.input_sequence = sequence;
+
+    // Returning an Err from the reducer would also roll back the recorded violation.
+    let Some(position) = validate_move(
+        ctx.sender,
+        entity.entity_id,
+        &entity.position,
+        position,
+        direction,
+        entity.stance.movement_speed_multiplier(),
+        ctx.timestamp,
+    ) else {
+        return;
+    };
+    entity.set_position(position);
+    entity.direction = direction;
+    entity.moving = moving;
 }
 
 #[spacetimedb(reducer)]
@@ -211,6 +393,9 @@ pub fn update_player_stance(
 ) -> Result<(), String> {
     if let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) {
         if let Some(mut entity) = EntityComponent::filter_by_entity_id(&player.entity_id) {
+            if entity.dead {
+                return Err("You are dead".to_string());
+            }
             entity.stance = stance;
             EntityComponent::update_by_entity_id(&player.entity_id, entity);
             return Ok(());
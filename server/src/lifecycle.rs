@@ -0,0 +1,64 @@
+use log::info;
+
+use crate::{
+    character::save_character,
+    combat::{CombatCooldown, DefenseWindow},
+    death::Death,
+    movement::MovementState,
+    npc::Npc,
+    spells::{clear_spell_state, SpellEntity},
+    status::clear_status_effects,
+    Client, EntityComponent, PlayerComponent,
+};
+
+// Deletes an entity together with every row keyed by its id. Anything that
+// removes an `EntityComponent` goes through here so nothing is left behind.
+pub fn despawn_entity(entity_id: u64) {
+    EntityComponent::delete_by_entity_id(&entity_id);
+    MovementState::delete_by_entity_id(&entity_id);
+    CombatCooldown::delete_by_entity_id(&entity_id);
+    DefenseWindow::delete_by_entity_id(&entity_id);
+    SpellEntity::delete_by_entity_id(&entity_id);
+    Npc::delete_by_entity_id(&entity_id);
+    Death::delete_by_entity_id(&entity_id);
+    clear_spell_state(entity_id);
+    clear_status_effects(entity_id);
+}
+
+// Takes a player out of the world. The character keeps the entity's last state.
+pub fn despawn_player(player: &PlayerComponent) {
+    if let Some(entity) = EntityComponent::filter_by_entity_id(&player.entity_id) {
+        save_character(&player.owner_id, &entity);
+    }
+    PlayerComponent::delete_by_owner_id(&player.owner_id);
+    despawn_entity(player.entity_id);
+    info!("Removed Player: {}", player.owner_id);
+}
+
+// Catches whatever slipped past the cascades above: players whose client is gone
+// without `client_disconnected` having cleaned up, and entities nothing owns.
+// Run periodically from `world_tick`.
+pub fn sweep_orphans() {
+    let gone: Vec<PlayerComponent> = PlayerComponent::iter()
+        .filter(|player| {
+            !Client::filter_by_client_id(&player.owner_id).is_some_and(|client| client.connected)
+                || EntityComponent::filter_by_entity_id(&player.entity_id).is_none()
+        })
+        .collect();
+    for player in gone {
+        despawn_player(&player);
+    }
+
+    let orphans: Vec<u64> = EntityComponent::iter()
+        .map(|entity| entity.entity_id)
+        .filter(|entity_id| {
+            PlayerComponent::filter_by_entity_id(entity_id).is_none()
+                && SpellEntity::filter_by_entity_id(entity_id).is_none()
+                && Npc::filter_by_entity_id(entity_id).is_none()
+        })
+        .collect();
+    for entity_id in orphans {
+        info!("Removing orphaned entity {}", entity_id);
+        despawn_entity(entity_id);
+    }
+}
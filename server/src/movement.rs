@@ -0,0 +1,315 @@
+use spacetimedb::{spacetimedb, Identity, SpacetimeType, Timestamp};
+use log::warn;
+
+use crate::{config::config, EntityComponent, StdbVector3};
+
+// Tunables used to validate client movement. We're using this table as a
+// singleton, so there should only be one row where the version is 0.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct MovementConfig {
+    #[primarykey]
+    pub version: u32,
+    // Vertical speed in units per second, kept separate so jumping and falling
+    // don't trip the horizontal check against `Config::max_speed`.
+    pub max_vertical_speed: f32,
+    // Multiplier applied on top of the speeds above to absorb frame jitter.
+    pub speed_tolerance: f32,
+    // Distance that is always allowed regardless of elapsed time, so two
+    // updates arriving in the same tick aren't treated as infinite speed.
+    pub grace_distance: f32,
+    // When true an oversized move is clamped to the allowed distance,
+    // otherwise the player stays at the last accepted position.
+    pub clamp_violations: bool,
+    // Cap on the time since the last accepted move that counts towards the
+    // allowed distance. Clients only send when something changed, so without it
+    // standing still would build up a budget for one huge jump.
+    pub max_elapsed_micros: u64,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            version: 0,
+            max_vertical_speed: 10.0,
+            speed_tolerance: 1.5,
+            grace_distance: 0.5,
+            clamp_violations: true,
+            // A few sends at the default 20 per second, see `ClientConfig::send_rate`.
+            max_elapsed_micros: 250_000,
+        }
+    }
+}
+
+// The last position the server accepted for an entity and when it did so.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct MovementState {
+    #[primarykey]
+    pub entity_id: u64,
+    pub last_position: StdbVector3,
+    pub last_update: Timestamp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum MovementViolationKind {
+    NonFinite,
+    SpeedExceeded,
+}
+
+// Audit log of rejected or clamped moves, so admins can see who is speed-hacking.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct MovementViolation {
+    #[primarykey]
+    #[autoinc]
+    pub violation_id: u64,
+    pub owner_id: Identity,
+    pub entity_id: u64,
+    pub kind: MovementViolationKind,
+    pub attempted: StdbVector3,
+    pub accepted: StdbVector3,
+    // Horizontal speed of the attempted move in units per second.
+    pub speed: f32,
+    pub timestamp: Timestamp,
+}
+
+pub fn init_movement_config() {
+    if MovementConfig::filter_by_version(&0).is_none() {
+        MovementConfig::insert(MovementConfig::default())
+            .expect("Failed to insert movement config");
+    }
+}
+
+pub fn movement_config() -> MovementConfig {
+    MovementConfig::filter_by_version(&0).unwrap_or_default()
+}
+
+fn is_finite(v: &StdbVector3) -> bool {
+    v.x.is_finite() && v.y.is_finite() && v.z.is_finite()
+}
+
+// Checks a requested position against the last accepted one and returns the
+// position that should be written to the entity. Violations are recorded in
+// the `MovementViolation` table. Returns `None` when the move must be dropped.
+pub fn validate_move(
+    owner_id: Identity,
+    entity_id: u64,
+    current: &StdbVector3,
+    requested: StdbVector3,
+    direction: f32,
+    speed_multiplier: f32,
+    now: Timestamp,
+) -> Option<StdbVector3> {
+    let state = MovementState::filter_by_entity_id(&entity_id).unwrap_or(MovementState {
+        entity_id,
+        last_position: current.clone(),
+        last_update: now,
+    });
+    let last = state.last_position.clone();
+
+    if !is_finite(&requested) || !direction.is_finite() {
+        warn!("Rejected non-finite move from entity {}", entity_id);
+        record_violation(owner_id, entity_id, MovementViolationKind::NonFinite, requested, last, 0.0, now);
+        return None;
+    }
+
+    let max_speed = config().max_speed;
+    let config = movement_config();
+    let elapsed_micros = now
+        .into_micros_since_epoch()
+        .saturating_sub(state.last_update.into_micros_since_epoch());
+
+    let accepted = match check_move(&config, max_speed * speed_multiplier, &last, &requested, elapsed_micros) {
+        MoveCheck::Allowed => requested,
+        MoveCheck::Exceeded { accepted, speed } => {
+            warn!("Entity {} moved at {} u/s, exceeding the speed cap", entity_id, speed);
+            record_violation(owner_id, entity_id, MovementViolationKind::SpeedExceeded, requested, accepted.clone(), speed, now);
+            accepted
+        }
+    };
+
+    let state = MovementState {
+        entity_id,
+        last_position: accepted.clone(),
+        last_update: now,
+    };
+    if MovementState::filter_by_entity_id(&entity_id).is_some() {
+        MovementState::update_by_entity_id(&entity_id, state);
+    } else {
+        MovementState::insert(state).expect("Failed to insert movement state");
+    }
+
+    Some(accepted)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum MoveCheck {
+    Allowed,
+    // The position to use instead and the horizontal speed that was attempted.
+    Exceeded { accepted: StdbVector3, speed: f32 },
+}
+
+// The speed check of `validate_move`, for a move from `last` to `requested`
+// `elapsed_micros` after the last accepted one at up to `max_speed`.
+fn check_move(
+    config: &MovementConfig,
+    max_speed: f32,
+    last: &StdbVector3,
+    requested: &StdbVector3,
+    elapsed_micros: u64,
+) -> MoveCheck {
+    let elapsed = elapsed_micros.min(config.max_elapsed_micros) as f32 / 1_000_000.0;
+
+    let dx = requested.x - last.x;
+    let dy = requested.y - last.y;
+    let dz = requested.z - last.z;
+    let horizontal = (dx * dx + dz * dz).sqrt();
+    let allowed_horizontal = max_speed * config.speed_tolerance * elapsed + config.grace_distance;
+    let allowed_vertical = config.max_vertical_speed * config.speed_tolerance * elapsed + config.grace_distance;
+
+    if horizontal <= allowed_horizontal && dy.abs() <= allowed_vertical {
+        return MoveCheck::Allowed;
+    }
+
+    let speed = if elapsed > 0.0 { horizontal / elapsed } else { f32::INFINITY };
+    let accepted = if config.clamp_violations {
+        let h_scale = if horizontal > allowed_horizontal { allowed_horizontal / horizontal } else { 1.0 };
+        StdbVector3 {
+            x: last.x + dx * h_scale,
+            y: last.y + dy.clamp(-allowed_vertical, allowed_vertical),
+            z: last.z + dz * h_scale,
+        }
+    } else {
+        last.clone()
+    };
+    MoveCheck::Exceeded { accepted, speed }
+}
+
+// Inputs the owning client sent before a teleport are skipped by jumping the
+// entity's `input_sequence` past them. Far more than can be in flight at once.
+const TELEPORT_SEQUENCE_SKIP: u32 = 1024;
+
+// Moves an entity without going through `validate_move`. The owning client sees
+// `input_sequence` jump past anything it sent and snaps to the new position.
+pub fn teleport_entity(entity: &mut EntityComponent, position: StdbVector3, now: Timestamp) {
+    entity.set_position(position.clone());
+    entity.moving = false;
+    entity.input_sequence = entity.input_sequence.saturating_add(TELEPORT_SEQUENCE_SKIP);
+
+    let state = MovementState {
+        entity_id: entity.entity_id,
+        last_position: position,
+        last_update: now,
+    };
+    if MovementState::filter_by_entity_id(&entity.entity_id).is_some() {
+        MovementState::update_by_entity_id(&entity.entity_id, state);
+    } else {
+        MovementState::insert(state).expect("Failed to insert movement state");
+    }
+}
+
+fn record_violation(
+    owner_id: Identity,
+    entity_id: u64,
+    kind: MovementViolationKind,
+    attempted: StdbVector3,
+    accepted: StdbVector3,
+    speed: f32,
+    timestamp: Timestamp,
+) {
+    MovementViolation::insert(MovementViolation {
+        violation_id: 0,
+        owner_id,
+        entity_id,
+        kind,
+        attempted,
+        accepted,
+        speed,
+        timestamp,
+    })
+    .expect("Failed to record movement violation");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f32, y: f32, z: f32) -> StdbVector3 {
+        StdbVector3 { x, y, z }
+    }
+
+    // A tenth of a second at 5 u/s with the default tolerance allows 0.75 + 0.5 grace.
+    const TENTH: u64 = 100_000;
+
+    #[test]
+    fn moves_within_the_speed_are_allowed() {
+        let config = MovementConfig::default();
+        let check = check_move(&config, 5.0, &point(0.0, 0.0, 0.0), &point(1.2, 0.0, 0.0), TENTH);
+        assert_eq!(check, MoveCheck::Allowed);
+    }
+
+    #[test]
+    fn grace_distance_covers_updates_in_the_same_tick() {
+        let config = MovementConfig::default();
+        let check = check_move(&config, 5.0, &point(0.0, 0.0, 0.0), &point(0.0, 0.0, 0.4), 0);
+        assert_eq!(check, MoveCheck::Allowed);
+    }
+
+    #[test]
+    fn fast_moves_are_clamped_to_the_allowed_distance() {
+        let config = MovementConfig::default();
+        let MoveCheck::Exceeded { accepted, speed } =
+            check_move(&config, 5.0, &point(0.0, 0.0, 0.0), &point(0.0, 0.0, 2.5), TENTH)
+        else {
+            panic!("expected a violation");
+        };
+        assert!((accepted.z - 1.25).abs() < 1e-5);
+        assert_eq!((accepted.x, accepted.y), (0.0, 0.0));
+        assert!((speed - 25.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn vertical_moves_are_clamped_separately() {
+        let config = MovementConfig::default();
+        // 10 u/s * 1.5 * 0.1 s + 0.5 grace.
+        let MoveCheck::Exceeded { accepted, .. } =
+            check_move(&config, 5.0, &point(0.0, 0.0, 0.0), &point(0.5, 5.0, 0.0), TENTH)
+        else {
+            panic!("expected a violation");
+        };
+        assert_eq!(accepted.x, 0.5);
+        assert!((accepted.y - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn without_clamping_the_player_stays_put() {
+        let config = MovementConfig {
+            clamp_violations: false,
+            ..MovementConfig::default()
+        };
+        let last = point(3.0, 0.0, 3.0);
+        let check = check_move(&config, 5.0, &last, &point(10.0, 0.0, 3.0), TENTH);
+        assert!(matches!(check, MoveCheck::Exceeded { accepted, .. } if accepted == last));
+    }
+
+    #[test]
+    fn standing_still_does_not_build_up_a_budget() {
+        let config = MovementConfig::default();
+        // A minute idle counts as `max_elapsed_micros`: 5 * 1.5 * 0.25 + 0.5.
+        let MoveCheck::Exceeded { accepted, .. } =
+            check_move(&config, 5.0, &point(0.0, 0.0, 0.0), &point(20.0, 0.0, 0.0), 60_000_000)
+        else {
+            panic!("expected a violation");
+        };
+        assert!((accepted.x - 2.375).abs() < 1e-5);
+    }
+
+    #[test]
+    fn stance_multiplier_is_part_of_the_speed() {
+        let config = MovementConfig::default();
+        let (from, to) = (point(0.0, 0.0, 0.0), point(1.2, 0.0, 0.0));
+        assert_eq!(check_move(&config, 5.0, &from, &to, TENTH), MoveCheck::Allowed);
+        assert_ne!(check_move(&config, 5.0 * 0.6, &from, &to, TENTH), MoveCheck::Allowed);
+    }
+}
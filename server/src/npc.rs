@@ -0,0 +1,594 @@
+use spacetimedb::{spacetimedb, SpacetimeType, Timestamp};
+use log::info;
+
+use crate::{
+    combat::{after, apply_damage, is_before, CombatEvent},
+    death::kill_entity,
+    lifecycle::despawn_entity,
+    EntityComponent, PlayerAction, PlayerComponent, PlayerStances, StdbVector3,
+};
+
+// How long a dead NPC stays in the world before it is removed.
+const CORPSE_MICROS: u64 = 5_000_000;
+// Pause at each patrol point, and before the first patrol.
+const IDLE_MICROS: u64 = 3_000_000;
+// Spawners fill up one NPC at a time, this far apart.
+const SPAWN_STAGGER_MICROS: u64 = 1_000_000;
+// Close enough to a patrol point or home to count as arrived.
+const ARRIVE_DISTANCE: f32 = 0.2;
+// NPCs stroll while patrolling and hurry home while leashing.
+const PATROL_SPEED_MULTIPLIER: f32 = 0.5;
+const LEASH_SPEED_MULTIPLIER: f32 = 1.5;
+// A target moving this far beyond attack range is chased again.
+const ATTACK_RANGE_SLACK: f32 = 1.2;
+// Hits this recent make an idle or patrolling NPC go after the attacker.
+const RETALIATE_MICROS: u64 = 1_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum NpcState {
+    // Standing at its spot until `next_action`.
+    Idle,
+    // Walking to `patrol_target`.
+    Patrol,
+    // Running after `target_id`.
+    Chase,
+    // In range of `target_id`, swinging every `attack_cooldown_micros`.
+    Attack,
+    // Too far from home, running back and ignoring everyone on the way.
+    Leash,
+    // Killed, removed at `next_action`.
+    Dead,
+}
+
+// Kinds of NPC and their stats. Clients use `name` to label them.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct NpcDef {
+    #[primarykey]
+    pub npc_def_id: u32,
+    pub name: String,
+    pub max_health: f32,
+    // Units per second while chasing.
+    pub speed: f32,
+    pub damage: f32,
+    pub aggro_range: f32,
+    pub attack_range: f32,
+    pub attack_cooldown_micros: u64,
+    // Distance from home at which a chase is given up.
+    pub leash_range: f32,
+}
+
+// The AI state of an NPC entity. The entity itself lives in `EntityComponent`
+// like any player's, so clients receive NPCs through the same cell subscription.
+#[spacetimedb(table(public))]
+#[derive(Clone, PartialEq)]
+pub struct Npc {
+    #[primarykey]
+    pub entity_id: u64,
+    pub npc_def_id: u32,
+    pub spawner_id: u32,
+    pub state: NpcState,
+    pub target_id: Option<u64>,
+    // Where it spawned, patrols around and returns to when leashing.
+    pub home: StdbVector3,
+    pub patrol_target: StdbVector3,
+    // End of an idle pause, the next attack or the corpse timer, depending on `state`.
+    pub next_action: Timestamp,
+}
+
+// Keeps up to `max_alive` NPCs of one kind within `radius` of `center`. After
+// one dies, the next spawn waits at least `respawn_micros`.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct NpcSpawner {
+    #[primarykey]
+    pub spawner_id: u32,
+    pub npc_def_id: u32,
+    pub center: StdbVector3,
+    pub radius: f32,
+    pub max_alive: u32,
+    pub respawn_micros: u64,
+    pub next_spawn: Timestamp,
+}
+
+pub fn init_npcs() {
+    let defs = [
+        NpcDef {
+            npc_def_id: 1,
+            name: "Goblin".to_string(),
+            max_health: 60.0,
+            speed: 1.6,
+            damage: 6.0,
+            aggro_range: 6.0,
+            attack_range: 0.7,
+            attack_cooldown_micros: 1_500_000,
+            leash_range: 15.0,
+        },
+        NpcDef {
+            npc_def_id: 2,
+            name: "Brute".to_string(),
+            max_health: 150.0,
+            speed: 1.2,
+            damage: 14.0,
+            aggro_range: 4.0,
+            attack_range: 0.9,
+            attack_cooldown_micros: 2_500_000,
+            leash_range: 10.0,
+        },
+    ];
+    for def in defs {
+        if NpcDef::filter_by_npc_def_id(&def.npc_def_id).is_none() {
+            NpcDef::insert(def).expect("Failed to insert npc def");
+        }
+    }
+
+    let spawners = [
+        (1, 1, StdbVector3 { x: 12.0, y: 0.245, z: 12.0 }, 5.0, 3, 20_000_000),
+        (2, 2, StdbVector3 { x: -14.0, y: 0.245, z: 10.0 }, 3.0, 1, 60_000_000),
+    ];
+    for (spawner_id, npc_def_id, center, radius, max_alive, respawn_micros) in spawners {
+        if NpcSpawner::filter_by_spawner_id(&spawner_id).is_none() {
+            NpcSpawner::insert(NpcSpawner {
+                spawner_id,
+                npc_def_id,
+                center,
+                radius,
+                max_alive,
+                respawn_micros,
+                next_spawn: Timestamp::from_micros_since_epoch(0),
+            })
+            .expect("Failed to insert npc spawner");
+        }
+    }
+}
+
+// Fills spawners that are below their count and whose timer has run out. Run from `world_tick`.
+pub fn update_spawners(now: Timestamp) {
+    for mut spawner in NpcSpawner::iter() {
+        if is_before(now, spawner.next_spawn) {
+            continue;
+        }
+        let alive = Npc::iter().filter(|npc| npc.spawner_id == spawner.spawner_id).count();
+        if alive >= spawner.max_alive as usize {
+            continue;
+        }
+        let Some(def) = NpcDef::filter_by_npc_def_id(&spawner.npc_def_id) else {
+            continue;
+        };
+
+        let position = random_point(&spawner.center, spawner.radius, spawner.spawner_id as u64 ^ now.into_micros_since_epoch());
+        spawn_npc(&def, spawner.spawner_id, position, now);
+
+        spawner.next_spawn = after(now, SPAWN_STAGGER_MICROS);
+        let spawner_id = spawner.spawner_id;
+        NpcSpawner::update_by_spawner_id(&spawner_id, spawner);
+    }
+}
+
+fn spawn_npc(def: &NpcDef, spawner_id: u32, position: StdbVector3, now: Timestamp) {
+    let mut entity = EntityComponent {
+        stance: PlayerStances::Combat,
+        health: def.max_health,
+        max_health: def.max_health,
+        ..Default::default()
+    };
+    entity.set_position(position.clone());
+    let entity_id = EntityComponent::insert(entity)
+        .expect("Failed to insert npc entity")
+        .entity_id;
+
+    Npc::insert(Npc {
+        entity_id,
+        npc_def_id: def.npc_def_id,
+        spawner_id,
+        state: NpcState::Idle,
+        target_id: None,
+        home: position.clone(),
+        patrol_target: position,
+        next_action: after(now, IDLE_MICROS),
+    })
+    .expect("Failed to insert npc");
+    info!("Spawned {} as entity {}", def.name, entity_id);
+}
+
+// Runs every NPC's state machine for one tick. Run from `world_tick`.
+pub fn update_npcs(seconds: f32, now: Timestamp) {
+    let npcs: Vec<Npc> = Npc::iter().collect();
+    for mut npc in npcs {
+        let Some(mut entity) = EntityComponent::filter_by_entity_id(&npc.entity_id) else {
+            continue;
+        };
+        let Some(def) = NpcDef::filter_by_npc_def_id(&npc.npc_def_id) else {
+            continue;
+        };
+
+        if npc.state == NpcState::Dead {
+            if !is_before(now, npc.next_action) {
+                despawn_entity(npc.entity_id);
+            }
+            continue;
+        }
+        if entity.health <= 0.0 {
+            die(&mut npc, &mut entity, now);
+            Npc::update_by_entity_id(&npc.entity_id, npc.clone());
+            EntityComponent::update_by_entity_id(&npc.entity_id, entity);
+            continue;
+        }
+
+        let npc_before = npc.clone();
+        let entity_before = (entity.position.clone(), entity.direction, entity.moving, entity.action.clone(), entity.health);
+        entity.action = PlayerAction::None;
+        // The states themselves only see what's looked up for them here.
+        match npc.state {
+            NpcState::Idle => {
+                let target_id = aggro_target(&npc, &def, &entity, now);
+                let radius = NpcSpawner::filter_by_spawner_id(&npc.spawner_id).map_or(0.0, |spawner| spawner.radius);
+                idle(&mut npc, target_id, radius, now);
+            }
+            NpcState::Patrol => {
+                let target_id = aggro_target(&npc, &def, &entity, now);
+                patrol(&mut npc, &def, &mut entity, target_id, seconds, now);
+            }
+            NpcState::Chase => chase(&mut npc, &def, &mut entity, living_target(&npc).as_ref(), seconds),
+            NpcState::Attack => {
+                let target = living_target(&npc);
+                let swings = attack(&mut npc, &def, &mut entity, target.as_ref(), now);
+                if let Some(target) = target.filter(|_| swings) {
+                    apply_damage(&entity, target, def.damage, now);
+                }
+            }
+            NpcState::Leash => leash(&mut npc, &def, &mut entity, seconds, now),
+            NpcState::Dead => {}
+        }
+        check_leash(&mut npc, &def, &entity);
+
+        // Only NPCs that moved, acted or changed state cost clients an update.
+        let entity_id = npc.entity_id;
+        if npc != npc_before {
+            Npc::update_by_entity_id(&entity_id, npc);
+        }
+        if (entity.position.clone(), entity.direction, entity.moving, entity.action.clone(), entity.health) != entity_before {
+            EntityComponent::update_by_entity_id(&entity_id, entity);
+        }
+    }
+}
+
+// `patrol_radius` is the spawner's, patrols stay within it around `home`.
+fn idle(npc: &mut Npc, target_id: Option<u64>, patrol_radius: f32, now: Timestamp) {
+    if engage(npc, target_id) {
+        return;
+    }
+    if !is_before(now, npc.next_action) {
+        npc.patrol_target = random_point(&npc.home, patrol_radius, npc.entity_id ^ now.into_micros_since_epoch());
+        npc.state = NpcState::Patrol;
+    }
+}
+
+fn patrol(npc: &mut Npc, def: &NpcDef, entity: &mut EntityComponent, target_id: Option<u64>, seconds: f32, now: Timestamp) {
+    if engage(npc, target_id) {
+        return;
+    }
+    let target = npc.patrol_target.clone();
+    if move_towards(entity, &target, def.speed * PATROL_SPEED_MULTIPLIER * seconds) {
+        npc.state = NpcState::Idle;
+        npc.next_action = after(now, IDLE_MICROS);
+    }
+}
+
+fn chase(npc: &mut Npc, def: &NpcDef, entity: &mut EntityComponent, target: Option<&EntityComponent>, seconds: f32) {
+    let Some(target) = target else {
+        npc.state = NpcState::Leash;
+        npc.target_id = None;
+        return;
+    };
+    if ground_distance(&entity.position, &target.position) <= def.attack_range {
+        entity.moving = false;
+        npc.state = NpcState::Attack;
+        return;
+    }
+    move_towards(entity, &target.position, def.speed * seconds);
+}
+
+// Returns whether the NPC swings at `target` this tick, the caller applies the damage.
+fn attack(npc: &mut Npc, def: &NpcDef, entity: &mut EntityComponent, target: Option<&EntityComponent>, now: Timestamp) -> bool {
+    let Some(target) = target else {
+        npc.state = NpcState::Leash;
+        npc.target_id = None;
+        return false;
+    };
+    entity.moving = false;
+    if ground_distance(&entity.position, &target.position) > def.attack_range * ATTACK_RANGE_SLACK {
+        npc.state = NpcState::Chase;
+        return false;
+    }
+    entity.direction = direction_to(&entity.position, &target.position);
+    if is_before(now, npc.next_action) {
+        return false;
+    }
+    entity.action = PlayerAction::Attack;
+    npc.next_action = after(now, def.attack_cooldown_micros);
+    true
+}
+
+// Heals up on the way, so kiting an NPC out of its area doesn't wear it down.
+fn leash(npc: &mut Npc, def: &NpcDef, entity: &mut EntityComponent, seconds: f32, now: Timestamp) {
+    let home = npc.home.clone();
+    entity.health = entity.max_health;
+    if move_towards(entity, &home, def.speed * LEASH_SPEED_MULTIPLIER * seconds) {
+        npc.state = NpcState::Idle;
+        npc.next_action = after(now, IDLE_MICROS);
+    }
+}
+
+// Chasing and attacking NPCs drop back once they're too far from home.
+fn check_leash(npc: &mut Npc, def: &NpcDef, entity: &EntityComponent) {
+    if matches!(npc.state, NpcState::Chase | NpcState::Attack)
+        && ground_distance(&entity.position, &npc.home) > def.leash_range
+    {
+        npc.state = NpcState::Leash;
+        npc.target_id = None;
+    }
+}
+
+fn die(npc: &mut Npc, entity: &mut EntityComponent, now: Timestamp) {
+    npc.state = NpcState::Dead;
+    npc.target_id = None;
+    npc.next_action = after(now, CORPSE_MICROS);
+    kill_entity(entity, now);
+
+    if let Some(mut spawner) = NpcSpawner::filter_by_spawner_id(&npc.spawner_id) {
+        let respawn = after(now, spawner.respawn_micros);
+        if is_before(spawner.next_spawn, respawn) {
+            spawner.next_spawn = respawn;
+            NpcSpawner::update_by_spawner_id(&npc.spawner_id, spawner);
+        }
+    }
+    info!("Npc {} died", npc.entity_id);
+}
+
+// Whoever just hit the NPC, otherwise the closest living player within aggro range.
+fn aggro_target(npc: &Npc, def: &NpcDef, entity: &EntityComponent, now: Timestamp) -> Option<u64> {
+    let attacker = CombatEvent::iter()
+        .filter(|event| event.target_id == npc.entity_id && is_before(now, after(event.timestamp, RETALIATE_MICROS)))
+        .map(|event| event.attacker_id)
+        .find(|attacker_id| PlayerComponent::filter_by_entity_id(attacker_id).is_some());
+    let closest = || {
+        PlayerComponent::iter()
+            .filter_map(|player| EntityComponent::filter_by_entity_id(&player.entity_id))
+            .filter(|player| player.health > 0.0)
+            .map(|player| (ground_distance(&entity.position, &player.position), player.entity_id))
+            .filter(|(distance, _)| *distance <= def.aggro_range)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, entity_id)| entity_id)
+    };
+
+    attacker.or_else(closest)
+}
+
+// Starts chasing `target_id` if there is one.
+fn engage(npc: &mut Npc, target_id: Option<u64>) -> bool {
+    let Some(target_id) = target_id else {
+        return false;
+    };
+    npc.target_id = Some(target_id);
+    npc.state = NpcState::Chase;
+    true
+}
+
+// The chased player, as long as they're still in the world and alive.
+fn living_target(npc: &Npc) -> Option<EntityComponent> {
+    let target_id = npc.target_id?;
+    PlayerComponent::filter_by_entity_id(&target_id)?;
+    EntityComponent::filter_by_entity_id(&target_id).filter(|target| target.health > 0.0)
+}
+
+// Steps up to `step` towards `target` on the ground plane and returns whether it arrived.
+fn move_towards(entity: &mut EntityComponent, target: &StdbVector3, step: f32) -> bool {
+    let distance = ground_distance(&entity.position, target);
+    if distance <= ARRIVE_DISTANCE.max(step) {
+        let position = StdbVector3 { y: entity.position.y, ..target.clone() };
+        entity.moving = distance > ARRIVE_DISTANCE;
+        entity.set_position(position);
+        return true;
+    }
+
+    let scale = step / distance;
+    entity.direction = direction_to(&entity.position, target);
+    let position = StdbVector3 {
+        x: entity.position.x + (target.x - entity.position.x) * scale,
+        y: entity.position.y,
+        z: entity.position.z + (target.z - entity.position.z) * scale,
+    };
+    entity.moving = true;
+    entity.set_position(position);
+    false
+}
+
+// The yaw facing `to`, see `combat::is_facing` for the convention.
+fn direction_to(from: &StdbVector3, to: &StdbVector3) -> f32 {
+    f32::atan2(-(to.x - from.x), -(to.z - from.z))
+}
+
+fn ground_distance(a: &StdbVector3, b: &StdbVector3) -> f32 {
+    let dx = b.x - a.x;
+    let dz = b.z - a.z;
+    (dx * dx + dz * dz).sqrt()
+}
+
+// A point within `radius` of `center`. Modules have no entropy of their own, so
+// `seed` mixes ids with the current time.
+fn random_point(center: &StdbVector3, radius: f32, seed: u64) -> StdbVector3 {
+    let angle = unit_random(seed) * std::f32::consts::TAU;
+    let distance = unit_random(seed.wrapping_add(1)).sqrt() * radius;
+    StdbVector3 {
+        x: center.x + angle.cos() * distance,
+        y: center.y,
+        z: center.z + angle.sin() * distance,
+    }
+}
+
+// splitmix64, mapped onto [0, 1).
+fn unit_random(seed: u64) -> f32 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(micros: u64) -> Timestamp {
+        Timestamp::from_micros_since_epoch(micros)
+    }
+
+    fn point(x: f32, z: f32) -> StdbVector3 {
+        StdbVector3 { x, y: 0.0, z }
+    }
+
+    fn goblin() -> NpcDef {
+        NpcDef {
+            npc_def_id: 1,
+            name: "Goblin".to_string(),
+            max_health: 60.0,
+            speed: 2.0,
+            damage: 6.0,
+            aggro_range: 6.0,
+            attack_range: 1.0,
+            attack_cooldown_micros: 1_000_000,
+            leash_range: 10.0,
+        }
+    }
+
+    fn npc(state: NpcState) -> Npc {
+        Npc {
+            entity_id: 1,
+            npc_def_id: 1,
+            spawner_id: 1,
+            state,
+            target_id: None,
+            home: point(0.0, 0.0),
+            patrol_target: point(0.0, 0.0),
+            next_action: at(IDLE_MICROS),
+        }
+    }
+
+    fn entity(x: f32, z: f32) -> EntityComponent {
+        let mut entity = EntityComponent {
+            health: 60.0,
+            max_health: 60.0,
+            ..Default::default()
+        };
+        entity.set_position(point(x, z));
+        entity
+    }
+
+    #[test]
+    fn idle_waits_then_patrols_around_home() {
+        let mut npc = npc(NpcState::Idle);
+        idle(&mut npc, None, 5.0, at(IDLE_MICROS - 1));
+        assert_eq!(npc.state, NpcState::Idle);
+
+        idle(&mut npc, None, 5.0, at(IDLE_MICROS));
+        assert_eq!(npc.state, NpcState::Patrol);
+        assert!(ground_distance(&npc.patrol_target, &npc.home) <= 5.0);
+    }
+
+    #[test]
+    fn idle_and_patrol_chase_a_target() {
+        let mut idling = npc(NpcState::Idle);
+        idle(&mut idling, Some(7), 5.0, at(0));
+        assert_eq!((idling.state, idling.target_id), (NpcState::Chase, Some(7)));
+
+        let mut patrolling = npc(NpcState::Patrol);
+        patrol(&mut patrolling, &goblin(), &mut entity(0.0, 0.0), Some(7), 0.1, at(0));
+        assert_eq!((patrolling.state, patrolling.target_id), (NpcState::Chase, Some(7)));
+    }
+
+    #[test]
+    fn patrol_idles_on_arrival() {
+        let mut npc = npc(NpcState::Patrol);
+        npc.patrol_target = point(3.0, 0.0);
+        let mut entity = entity(0.0, 0.0);
+
+        patrol(&mut npc, &goblin(), &mut entity, None, 1.0, at(0));
+        assert_eq!(npc.state, NpcState::Patrol);
+        assert!(entity.moving);
+
+        patrol(&mut npc, &goblin(), &mut entity, None, 10.0, at(5));
+        assert_eq!(npc.state, NpcState::Idle);
+        assert_eq!(npc.next_action, at(5 + IDLE_MICROS));
+        assert_eq!(entity.position, point(3.0, 0.0));
+    }
+
+    #[test]
+    fn chase_attacks_in_range_and_leashes_without_a_target() {
+        let mut npc = npc(NpcState::Chase);
+        npc.target_id = Some(7);
+        let mut goblin_entity = entity(0.0, 0.0);
+
+        chase(&mut npc, &goblin(), &mut goblin_entity, Some(&entity(3.0, 0.0)), 0.5);
+        assert_eq!(npc.state, NpcState::Chase);
+        assert_eq!(goblin_entity.position, point(1.0, 0.0));
+
+        chase(&mut npc, &goblin(), &mut goblin_entity, Some(&entity(1.5, 0.0)), 0.5);
+        assert_eq!(npc.state, NpcState::Attack);
+
+        chase(&mut npc, &goblin(), &mut goblin_entity, None, 0.5);
+        assert_eq!((npc.state, npc.target_id), (NpcState::Leash, None));
+    }
+
+    #[test]
+    fn attack_swings_on_cooldown() {
+        let mut npc = npc(NpcState::Attack);
+        npc.next_action = at(0);
+        let target = entity(1.0, 0.0);
+        let mut goblin_entity = entity(0.0, 0.0);
+
+        assert!(attack(&mut npc, &goblin(), &mut goblin_entity, Some(&target), at(0)));
+        assert_eq!(goblin_entity.action, PlayerAction::Attack);
+        assert_eq!(npc.next_action, at(1_000_000));
+        assert!(!attack(&mut npc, &goblin(), &mut goblin_entity, Some(&target), at(500_000)));
+        assert_eq!(npc.state, NpcState::Attack);
+    }
+
+    #[test]
+    fn attack_chases_only_past_the_slack() {
+        let mut npc = npc(NpcState::Attack);
+        let mut goblin_entity = entity(0.0, 0.0);
+
+        attack(&mut npc, &goblin(), &mut goblin_entity, Some(&entity(1.1, 0.0)), at(0));
+        assert_eq!(npc.state, NpcState::Attack);
+
+        assert!(!attack(&mut npc, &goblin(), &mut goblin_entity, Some(&entity(1.5, 0.0)), at(0)));
+        assert_eq!(npc.state, NpcState::Chase);
+    }
+
+    #[test]
+    fn too_far_from_home_leashes_back_healed() {
+        let mut npc = npc(NpcState::Chase);
+        npc.target_id = Some(7);
+        let mut goblin_entity = entity(11.0, 0.0);
+        goblin_entity.health = 10.0;
+
+        check_leash(&mut npc, &goblin(), &goblin_entity);
+        assert_eq!((npc.state, npc.target_id), (NpcState::Leash, None));
+
+        leash(&mut npc, &goblin(), &mut goblin_entity, 1.0, at(0));
+        assert_eq!(goblin_entity.health, goblin_entity.max_health);
+        assert_eq!(npc.state, NpcState::Leash);
+
+        leash(&mut npc, &goblin(), &mut goblin_entity, 10.0, at(0));
+        assert_eq!(npc.state, NpcState::Idle);
+        assert_eq!(goblin_entity.position, npc.home);
+    }
+
+    #[test]
+    fn patrolling_npcs_are_not_leashed() {
+        let mut npc = npc(NpcState::Patrol);
+        check_leash(&mut npc, &goblin(), &entity(20.0, 0.0));
+        assert_eq!(npc.state, NpcState::Patrol);
+    }
+}
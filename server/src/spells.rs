@@ -0,0 +1,449 @@
+use std::time::Duration;
+
+use spacetimedb::{spacetimedb, ReducerContext, SpacetimeType, Timestamp};
+use log::info;
+
+use crate::{
+    combat::{after, apply_damage, is_before},
+    lifecycle::despawn_entity,
+    entities_around, EntityComponent, PlayerAction, PlayerComponent, StdbVector3,
+};
+
+// How far from its center an entity can be hit, on top of the spell's radius.
+const ENTITY_RADIUS: f32 = 0.3;
+// Height above the caster's feet projectiles are launched from.
+const PROJECTILE_HEIGHT: f32 = 1.0;
+// How long an area spell stays around after it went off, for the client effect.
+const AREA_LINGER_MICROS: u64 = 500_000;
+// How often `tick_projectile` moves a projectile.
+const PROJECTILE_STEP_MICROS: u64 = 50_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum SpellEffect {
+    // Flies along the caster's facing until it hits something or reaches `range`.
+    Projectile,
+    // Goes off `range` in front of the caster and hits everything within `radius`.
+    Area,
+    // Restores `power` health to the caster.
+    Heal,
+}
+
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct SpellDef {
+    #[primarykey]
+    pub spell_id: u32,
+    pub name: String,
+    pub mana_cost: f32,
+    pub cast_time_micros: u64,
+    pub cooldown_micros: u64,
+    pub range: f32,
+    pub radius: f32,
+    // Units per second, only used by projectiles.
+    pub speed: f32,
+    pub power: f32,
+    pub effect: SpellEffect,
+}
+
+// A cast in progress. Clients draw cast bars from it; `resolve_spell` removes it.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct SpellCast {
+    #[primarykey]
+    #[autoinc]
+    pub cast_id: u64,
+    pub caster_id: u64,
+    pub spell_id: u32,
+    pub started: Timestamp,
+    pub finishes: Timestamp,
+}
+
+// When an entity may cast a spell again.
+#[spacetimedb(table)]
+#[derive(Clone)]
+pub struct SpellCooldown {
+    #[primarykey]
+    #[autoinc]
+    pub cooldown_id: u64,
+    pub entity_id: u64,
+    pub spell_id: u32,
+    pub ready_at: Timestamp,
+}
+
+// Marks an `EntityComponent` as a projectile or area effect rather than a player.
+// Position and direction are replicated through the entity like any other.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct SpellEntity {
+    #[primarykey]
+    pub entity_id: u64,
+    pub spell_id: u32,
+    pub caster_id: u64,
+    pub effect: SpellEffect,
+    pub origin: StdbVector3,
+    pub last_update: Timestamp,
+    pub expires: Timestamp,
+    // Projectile steps taken so far, see `tick_projectile`.
+    pub step: u32,
+}
+
+pub fn init_spell_defs() {
+    let defs = [
+        SpellDef {
+            spell_id: 1,
+            name: "Firebolt".to_string(),
+            mana_cost: 15.0,
+            cast_time_micros: 800_000,
+            cooldown_micros: 1_500_000,
+            range: 8.0,
+            radius: 0.2,
+            speed: 6.0,
+            power: 20.0,
+            effect: SpellEffect::Projectile,
+        },
+        SpellDef {
+            spell_id: 2,
+            name: "Shockwave".to_string(),
+            mana_cost: 25.0,
+            cast_time_micros: 1_200_000,
+            cooldown_micros: 6_000_000,
+            range: 2.0,
+            radius: 1.5,
+            speed: 0.0,
+            power: 15.0,
+            effect: SpellEffect::Area,
+        },
+        SpellDef {
+            spell_id: 3,
+            name: "Mend".to_string(),
+            mana_cost: 20.0,
+            cast_time_micros: 1_500_000,
+            cooldown_micros: 8_000_000,
+            range: 0.0,
+            radius: 0.0,
+            speed: 0.0,
+            power: 30.0,
+            effect: SpellEffect::Heal,
+        },
+    ];
+
+    for def in defs {
+        if SpellDef::filter_by_spell_id(&def.spell_id).is_none() {
+            SpellDef::insert(def).expect("Failed to insert spell definition");
+        }
+    }
+}
+
+// Starts casting `spell_id`. Mana and cooldown are checked now and again when
+// the cast finishes, since the caster might have spent the mana in between.
+#[spacetimedb(reducer)]
+pub fn cast_spell(ctx: ReducerContext, spell_id: u32) -> Result<(), String> {
+    let Some(player) = PlayerComponent::filter_by_owner_id(&ctx.sender) else {
+        return Err("Player not found".to_string());
+    };
+    let Some(mut caster) = EntityComponent::filter_by_entity_id(&player.entity_id) else {
+        return Err("Player not found".to_string());
+    };
+    let Some(def) = SpellDef::filter_by_spell_id(&spell_id) else {
+        return Err("Unknown spell".to_string());
+    };
+
+    if SpellCast::filter_by_caster_id(&caster.entity_id).next().is_some() {
+        return Err("Already casting".to_string());
+    }
+    check_can_cast(&caster, &def, ctx.timestamp)?;
+
+    let finishes = after(ctx.timestamp, def.cast_time_micros);
+    let cast_id = SpellCast::insert(SpellCast {
+        cast_id: 0,
+        caster_id: caster.entity_id,
+        spell_id,
+        started: ctx.timestamp,
+        finishes,
+    })
+    .expect("Failed to insert spell cast")
+    .cast_id;
+
+    caster.action = PlayerAction::CastSpell(spell_id);
+    let entity_id = caster.entity_id;
+    EntityComponent::update_by_entity_id(&entity_id, caster);
+
+    spacetimedb::schedule!(Duration::from_micros(def.cast_time_micros), resolve_spell(_, cast_id));
+    info!("{} started casting {}", ctx.sender, def.name);
+
+    Ok(())
+}
+
+// Scheduled by `cast_spell` for when the cast finishes. Casts whose caster died
+// or left in the meantime fizzle.
+#[spacetimedb(reducer)]
+pub fn resolve_spell(ctx: ReducerContext, cast_id: u64) -> Result<(), String> {
+    let Some(cast) = SpellCast::filter_by_cast_id(&cast_id) else {
+        return Ok(());
+    };
+    // Anyone can call a reducer, so don't let a client finish a cast early.
+    if is_before(ctx.timestamp, cast.finishes) {
+        return Err("Cast hasn't finished yet".to_string());
+    }
+    SpellCast::delete_by_cast_id(&cast_id);
+
+    let Some(mut caster) = EntityComponent::filter_by_entity_id(&cast.caster_id) else {
+        return Ok(());
+    };
+    let Some(def) = SpellDef::filter_by_spell_id(&cast.spell_id) else {
+        return Ok(());
+    };
+    if let Err(reason) = check_can_cast(&caster, &def, ctx.timestamp) {
+        info!("Cast of {} by {} fizzled: {}", def.name, caster.entity_id, reason);
+        return Ok(());
+    }
+
+    caster.mana -= def.mana_cost;
+    if caster.action == PlayerAction::CastSpell(def.spell_id) {
+        caster.action = PlayerAction::None;
+    }
+    set_cooldown(caster.entity_id, def.spell_id, after(ctx.timestamp, def.cooldown_micros));
+
+    match def.effect {
+        SpellEffect::Heal => {
+            caster.health = (caster.health + def.power).min(caster.max_health);
+        }
+        SpellEffect::Projectile => {
+            let mut position = caster.position.clone();
+            position.y += PROJECTILE_HEIGHT;
+            let entity_id = spawn_spell_entity(&caster, &def, position, ctx.timestamp);
+            spacetimedb::schedule!(Duration::from_micros(PROJECTILE_STEP_MICROS), tick_projectile(_, entity_id, 0));
+        }
+        SpellEffect::Area => {
+            let (facing_x, facing_z) = facing(caster.direction);
+            let center = StdbVector3 {
+                x: caster.position.x + facing_x * def.range,
+                y: caster.position.y,
+                z: caster.position.z + facing_z * def.range,
+            };
+            let entity_id = spawn_spell_entity(&caster, &def, center.clone(), ctx.timestamp);
+            let targets: Vec<EntityComponent> = entities_around(&center, def.radius + ENTITY_RADIUS)
+                .into_iter()
+                .filter(|target| is_target(target, caster.entity_id))
+                .filter(|target| ground_distance(&center, &target.position) <= def.radius + ENTITY_RADIUS)
+                .collect();
+            let source = source_at(&caster, &center);
+            for target in targets {
+                apply_damage(&source, target, def.power, ctx.timestamp);
+            }
+            spacetimedb::schedule!(Duration::from_micros(AREA_LINGER_MICROS), expire_spell_entity(_, entity_id));
+        }
+    }
+
+    let caster_id = caster.entity_id;
+    EntityComponent::update_by_entity_id(&caster_id, caster);
+
+    Ok(())
+}
+
+// Moves a projectile along its path, reschedules itself every step until it hits
+// something or runs out of range. `step` is the projectile's step count when this
+// call was scheduled.
+#[spacetimedb(reducer)]
+pub fn tick_projectile(ctx: ReducerContext, entity_id: u64, step: u32) -> Result<(), String> {
+    let Some(mut spell) = SpellEntity::filter_by_entity_id(&entity_id) else {
+        return Ok(());
+    };
+    // Like `world_tick`, only the scheduled call for the next step gets through, so
+    // clients calling this can't start a second loop or step it early.
+    if step != spell.step {
+        return Err("Step already ran".to_string());
+    }
+    if is_before(ctx.timestamp, after(spell.last_update, PROJECTILE_STEP_MICROS / 2)) {
+        return Err("Too early for the next step".to_string());
+    }
+    let Some(mut projectile) = EntityComponent::filter_by_entity_id(&entity_id) else {
+        SpellEntity::delete_by_entity_id(&entity_id);
+        return Ok(());
+    };
+    let Some(def) = SpellDef::filter_by_spell_id(&spell.spell_id) else {
+        despawn_spell_entity(entity_id);
+        return Ok(());
+    };
+
+    // Step by the real time passed, in case the scheduled call runs late.
+    let elapsed = ctx.timestamp.into_micros_since_epoch()
+        .saturating_sub(spell.last_update.into_micros_since_epoch()) as f32 / 1_000_000.0;
+    let (facing_x, facing_z) = facing(projectile.direction);
+    let mut position = projectile.position.clone();
+    position.x += facing_x * def.speed * elapsed;
+    position.z += facing_z * def.speed * elapsed;
+    projectile.set_position(position);
+
+    let hit = entities_around(&projectile.position, def.radius + ENTITY_RADIUS)
+        .into_iter()
+        .filter(|target| is_target(target, spell.caster_id))
+        .find(|target| ground_distance(&projectile.position, &target.position) <= def.radius + ENTITY_RADIUS);
+    if let Some(target) = hit {
+        let source = match EntityComponent::filter_by_entity_id(&spell.caster_id) {
+            Some(caster) => source_at(&caster, &projectile.position),
+            None => projectile.clone(),
+        };
+        apply_damage(&source, target, def.power, ctx.timestamp);
+        despawn_spell_entity(entity_id);
+        return Ok(());
+    }
+
+    if ground_distance(&spell.origin, &projectile.position) >= def.range || !is_before(ctx.timestamp, spell.expires) {
+        despawn_spell_entity(entity_id);
+        return Ok(());
+    }
+
+    EntityComponent::update_by_entity_id(&entity_id, projectile);
+    spell.last_update = ctx.timestamp;
+    spell.step += 1;
+    let step = spell.step;
+    SpellEntity::update_by_entity_id(&entity_id, spell);
+    spacetimedb::schedule!(Duration::from_micros(PROJECTILE_STEP_MICROS), tick_projectile(_, entity_id, step));
+
+    Ok(())
+}
+
+#[spacetimedb(reducer)]
+pub fn expire_spell_entity(ctx: ReducerContext, entity_id: u64) -> Result<(), String> {
+    let Some(spell) = SpellEntity::filter_by_entity_id(&entity_id) else {
+        return Ok(());
+    };
+    if is_before(ctx.timestamp, spell.expires) {
+        return Err("Spell hasn't expired yet".to_string());
+    }
+    despawn_spell_entity(entity_id);
+    Ok(())
+}
+
+// Drops casts and cooldowns of an entity that left the world.
+pub fn clear_spell_state(entity_id: u64) {
+    for cast in SpellCast::filter_by_caster_id(&entity_id).collect::<Vec<_>>() {
+        SpellCast::delete_by_cast_id(&cast.cast_id);
+    }
+    for cooldown in SpellCooldown::filter_by_entity_id(&entity_id).collect::<Vec<_>>() {
+        SpellCooldown::delete_by_cooldown_id(&cooldown.cooldown_id);
+    }
+}
+
+pub fn expire_spell_cooldowns(now: Timestamp) {
+    let expired: Vec<u64> = SpellCooldown::iter()
+        .filter(|c| !is_before(now, c.ready_at))
+        .map(|c| c.cooldown_id)
+        .collect();
+    for cooldown_id in expired {
+        SpellCooldown::delete_by_cooldown_id(&cooldown_id);
+    }
+}
+
+// Spell entities whose scheduled expiry never ran, e.g. because a projectile
+// tick failed. Given a second past `expires` so the scheduled path goes first.
+pub fn despawn_expired_spell_entities(now: Timestamp) {
+    let stale: Vec<u64> = SpellEntity::iter()
+        .filter(|s| !is_before(now, after(s.expires, 1_000_000)))
+        .map(|s| s.entity_id)
+        .collect();
+    for entity_id in stale {
+        despawn_spell_entity(entity_id);
+    }
+}
+
+// Casting takes the caster's whole attention. Other actions wait until the cast
+// resolves, and `update_player_state` leaves the action alone.
+pub fn is_casting(entity: &EntityComponent) -> bool {
+    matches!(entity.action, PlayerAction::CastSpell(_))
+}
+
+fn check_can_cast(caster: &EntityComponent, def: &SpellDef, now: Timestamp) -> Result<(), String> {
+    if caster.health <= 0.0 {
+        return Err("Dead players can't cast".to_string());
+    }
+    if caster.mana < def.mana_cost {
+        return Err("Not enough mana".to_string());
+    }
+    let on_cooldown = SpellCooldown::filter_by_entity_id(&caster.entity_id)
+        .any(|c| c.spell_id == def.spell_id && is_before(now, c.ready_at));
+    if on_cooldown {
+        return Err(format!("{} is on cooldown", def.name));
+    }
+    Ok(())
+}
+
+fn set_cooldown(entity_id: u64, spell_id: u32, ready_at: Timestamp) {
+    let existing = SpellCooldown::filter_by_entity_id(&entity_id).find(|c| c.spell_id == spell_id);
+    match existing {
+        Some(mut cooldown) => {
+            cooldown.ready_at = ready_at;
+            let cooldown_id = cooldown.cooldown_id;
+            SpellCooldown::update_by_cooldown_id(&cooldown_id, cooldown);
+        }
+        None => {
+            SpellCooldown::insert(SpellCooldown {
+                cooldown_id: 0,
+                entity_id,
+                spell_id,
+                ready_at,
+            })
+            .expect("Failed to insert spell cooldown");
+        }
+    }
+}
+
+fn spawn_spell_entity(caster: &EntityComponent, def: &SpellDef, position: StdbVector3, now: Timestamp) -> u64 {
+    let lifetime = match def.effect {
+        // Twice the time it needs to cover its range, as a backstop.
+        SpellEffect::Projectile if def.speed > 0.0 => (2.0 * def.range / def.speed * 1_000_000.0) as u64,
+        _ => AREA_LINGER_MICROS,
+    };
+    let mut entity = EntityComponent {
+        direction: caster.direction,
+        moving: def.effect == SpellEffect::Projectile,
+        ..Default::default()
+    };
+    entity.set_position(position.clone());
+    let entity_id = EntityComponent::insert(entity)
+        .expect("Failed to insert spell entity")
+        .entity_id;
+
+    SpellEntity::insert(SpellEntity {
+        entity_id,
+        spell_id: def.spell_id,
+        caster_id: caster.entity_id,
+        effect: def.effect,
+        origin: position,
+        last_update: now,
+        expires: after(now, lifetime),
+        step: 0,
+    })
+    .expect("Failed to insert spell entity");
+
+    entity_id
+}
+
+fn despawn_spell_entity(entity_id: u64) {
+    despawn_entity(entity_id);
+}
+
+// Living entities other than the caster. Spell entities have no health, so they're skipped too.
+fn is_target(entity: &EntityComponent, caster_id: u64) -> bool {
+    entity.entity_id != caster_id && entity.health > 0.0
+}
+
+// The caster as seen from where the spell hit, so blocks face the impact rather than the caster.
+fn source_at(caster: &EntityComponent, position: &StdbVector3) -> EntityComponent {
+    EntityComponent {
+        position: position.clone(),
+        ..caster.clone()
+    }
+}
+
+// See `combat::is_facing` for how `direction` maps onto the ground plane.
+fn facing(direction: f32) -> (f32, f32) {
+    (-direction.sin(), -direction.cos())
+}
+
+fn ground_distance(a: &StdbVector3, b: &StdbVector3) -> f32 {
+    let dx = b.x - a.x;
+    let dz = b.z - a.z;
+    (dx * dx + dz * dz).sqrt()
+}
@@ -0,0 +1,44 @@
+use crate::PlayerStances;
+
+// Per-stance tuning. NonCombat is for travelling, Combat is the all-rounder,
+// Precise trades mobility and defence for damage and Defensive the other way round.
+impl PlayerStances {
+    // Scales `Config::max_speed`. The client mirrors these values in
+    // player/movement.rs so its prediction matches what the server accepts.
+    pub fn movement_speed_multiplier(&self) -> f32 {
+        match self {
+            PlayerStances::NonCombat => 1.0,
+            PlayerStances::Combat => 0.85,
+            PlayerStances::Precise => 0.7,
+            PlayerStances::Defensive => 0.6,
+        }
+    }
+
+    pub fn damage_dealt_multiplier(&self) -> f32 {
+        match self {
+            PlayerStances::NonCombat => 0.0,
+            PlayerStances::Combat => 1.0,
+            PlayerStances::Precise => 1.25,
+            PlayerStances::Defensive => 0.75,
+        }
+    }
+
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        match self {
+            PlayerStances::NonCombat => 1.25,
+            PlayerStances::Combat => 1.0,
+            PlayerStances::Precise => 1.15,
+            PlayerStances::Defensive => 0.6,
+        }
+    }
+
+    // Share of the damage that still gets through a block.
+    pub fn block_damage_multiplier(&self) -> f32 {
+        match self {
+            PlayerStances::NonCombat => 1.0,
+            PlayerStances::Combat => 0.3,
+            PlayerStances::Precise => 0.5,
+            PlayerStances::Defensive => 0.1,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use spacetimedb::{spacetimedb, SpacetimeType, Timestamp};
+
+use crate::{
+    combat::{after, is_before},
+    EntityComponent,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SpacetimeType)]
+pub enum StatusEffectKind {
+    // Restores `magnitude` health per second.
+    Regeneration,
+    // Deals `magnitude` damage per second, never below 1 health.
+    Poison,
+}
+
+// A timed effect on an entity, applied by `world_tick` until it expires.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct StatusEffect {
+    #[primarykey]
+    #[autoinc]
+    pub effect_id: u64,
+    pub entity_id: u64,
+    pub kind: StatusEffectKind,
+    pub magnitude: f32,
+    pub expires: Timestamp,
+}
+
+pub fn add_status_effect(entity_id: u64, kind: StatusEffectKind, magnitude: f32, duration_micros: u64, now: Timestamp) {
+    StatusEffect::insert(StatusEffect {
+        effect_id: 0,
+        entity_id,
+        kind,
+        magnitude,
+        expires: after(now, duration_micros),
+    })
+    .expect("Failed to insert status effect");
+}
+
+// Applies `seconds` worth of the entity's active effects.
+pub fn apply_status_effects(entity: &mut EntityComponent, seconds: f32, now: Timestamp) {
+    for effect in StatusEffect::filter_by_entity_id(&entity.entity_id) {
+        if !is_before(now, effect.expires) {
+            continue;
+        }
+        match effect.kind {
+            StatusEffectKind::Regeneration => {
+                entity.health = (entity.health + effect.magnitude * seconds).min(entity.max_health);
+            }
+            StatusEffectKind::Poison => {
+                entity.health = (entity.health - effect.magnitude * seconds).max(1.0);
+            }
+        }
+    }
+}
+
+pub fn expire_status_effects(now: Timestamp) {
+    let expired: Vec<u64> = StatusEffect::iter()
+        .filter(|effect| !is_before(now, effect.expires))
+        .map(|effect| effect.effect_id)
+        .collect();
+    for effect_id in expired {
+        StatusEffect::delete_by_effect_id(&effect_id);
+    }
+}
+
+pub fn clear_status_effects(entity_id: u64) {
+    for effect in StatusEffect::filter_by_entity_id(&entity_id).collect::<Vec<_>>() {
+        StatusEffect::delete_by_effect_id(&effect.effect_id);
+    }
+}
@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use spacetimedb::{spacetimedb, ReducerContext, Timestamp};
+
+use crate::{
+    admin::expire_kicks,
+    chat::prune_chat,
+    combat::{after, expire_combat_timers, is_before, prune_combat_events},
+    config::config,
+    lifecycle::sweep_orphans,
+    npc::{update_npcs, update_spawners},
+    spells::{despawn_expired_spell_entities, expire_spell_cooldowns},
+    status::{apply_status_effects, expire_status_effects},
+    EntityComponent,
+};
+
+// A tick never simulates more than this, e.g. the first one or after the module was down.
+const MAX_TICK_SECONDS: f32 = 1.0;
+// Every fifty ticks, five seconds at the default rate, the sweep walks all players and entities.
+const SWEEP_INTERVAL_TICKS: u64 = 50;
+
+// Per second, for every living entity.
+const HEALTH_REGEN: f32 = 0.5;
+const STAMINA_REGEN: f32 = 8.0;
+const MANA_REGEN: f32 = 2.0;
+
+// Singleton with version 0, like `MovementConfig`. Advanced by every `world_tick`.
+#[spacetimedb(table(public))]
+#[derive(Clone)]
+pub struct WorldClock {
+    #[primarykey]
+    pub version: u32,
+    pub tick: u64,
+    // When the last tick ran.
+    pub server_time: Timestamp,
+    // Ticks before this are rejected, see `world_tick`.
+    pub next_tick: Timestamp,
+}
+
+// Creates the clock and starts the tick loop. Only called from `init`, the loop
+// reschedules itself from then on.
+pub fn init_world_clock() {
+    let tick = world_clock().unwrap_or_else(insert_world_clock).tick;
+    spacetimedb::schedule!(Duration::from_micros(config().tick_micros), world_tick(_, tick));
+}
+
+fn insert_world_clock() -> WorldClock {
+    WorldClock::insert(WorldClock {
+        version: 0,
+        tick: 0,
+        server_time: Timestamp::from_micros_since_epoch(0),
+        next_tick: Timestamp::from_micros_since_epoch(0),
+    })
+    .expect("Failed to insert world clock")
+}
+
+pub fn world_clock() -> Option<WorldClock> {
+    WorldClock::filter_by_version(&0)
+}
+
+// Advances the world by the time since the last tick: regeneration and status
+// effects on entities, then expiry of cooldowns and leftovers nothing else cleans up.
+// `tick` is the clock's tick count when this call was scheduled.
+#[spacetimedb(reducer)]
+pub fn world_tick(ctx: ReducerContext, tick: u64) -> Result<(), String> {
+    // Clients can call reducers too, but scheduled calls are made by the module
+    // itself. Turning everyone else away keeps clients from speeding up the world.
+    if ctx.sender != spacetimedb::identity() {
+        return Err("The world tick is only run by the server".to_string());
+    }
+    let now = ctx.timestamp;
+    let Some(mut clock) = world_clock() else {
+        // Nothing else would restart the loop, so start over with a new clock.
+        log::error!("World clock missing, recreating it");
+        insert_world_clock();
+        spacetimedb::schedule!(Duration::from_micros(config().tick_micros), world_tick(_, 0));
+        return Ok(());
+    };
+    // Only a second loop, e.g. from `init` running again, gets here with a tick
+    // that already ran or too early. It ends here and the original one carries on.
+    if tick != clock.tick {
+        return Err("Tick already ran".to_string());
+    }
+    if is_before(now, clock.next_tick) {
+        return Err("Too early for the next tick".to_string());
+    }
+
+    let elapsed = now
+        .into_micros_since_epoch()
+        .saturating_sub(clock.server_time.into_micros_since_epoch());
+    let seconds = (elapsed as f32 / 1_000_000.0).min(MAX_TICK_SECONDS);
+
+    update_entities(seconds, now);
+    update_npcs(seconds, now);
+    update_spawners(now);
+    expire_status_effects(now);
+    expire_combat_timers(now);
+    expire_spell_cooldowns(now);
+    despawn_expired_spell_entities(now);
+    prune_combat_events(now);
+    prune_chat(now);
+    expire_kicks(now);
+    if clock.tick % SWEEP_INTERVAL_TICKS == 0 {
+        sweep_orphans();
+    }
+
+    clock.tick += 1;
+    clock.server_time = now;
+    // Half a tick of slack so the scheduled call is never early.
+    let tick_micros = config().tick_micros;
+    clock.next_tick = after(now, tick_micros / 2);
+    let tick = clock.tick;
+    WorldClock::update_by_version(&0, clock);
+
+    spacetimedb::schedule!(Duration::from_micros(tick_micros), world_tick(_, tick));
+    Ok(())
+}
+
+// Only entities that actually changed are written, so idle full-health players
+// don't cost clients an update every tick.
+fn update_entities(seconds: f32, now: Timestamp) {
+    for mut entity in EntityComponent::iter() {
+        // Dead players, NPC corpses and spell entities don't regenerate.
+        if entity.health <= 0.0 {
+            continue;
+        }
+        let before = (entity.health, entity.stamina, entity.mana);
+
+        entity.health = (entity.health + HEALTH_REGEN * seconds).min(entity.max_health);
+        entity.stamina = (entity.stamina + STAMINA_REGEN * seconds).min(entity.max_stamina);
+        entity.mana = (entity.mana + MANA_REGEN * seconds).min(entity.max_mana);
+        apply_status_effects(&mut entity, seconds, now);
+
+        if (entity.health, entity.stamina, entity.mana) != before {
+            let entity_id = entity.entity_id;
+            EntityComponent::update_by_entity_id(&entity_id, entity);
+        }
+    }
+}
@@ -4,7 +4,7 @@ pub mod setup_animations;
 use bevy::{animation::animate_targets, prelude::*};
 use run_animations::{link_animations, play_animation};
 use setup_animations::{get_animations, AnimationController, Animations};
-use crate::{asset_manager::AssetLoadingState, player::HandleScenesState};
+use crate::{asset_manager::AssetLoadingState, network::player_updates::handle_player_updates, player::HandleScenesState};
 
 
 pub struct AnimPlugin;
@@ -15,6 +15,6 @@ impl Plugin for AnimPlugin {
         .add_systems(OnEnter(AssetLoadingState::LoadingAnimations), get_animations)
         //.add_systems(OnEnter(AnimationLoadingState::LoadingSetup))
         //.add_systems(OnEnter(HandleScenesState::Spawned), animation_list)
-        .add_systems(Update, (link_animations, play_animation.before(animate_targets)).run_if(in_state(HandleScenesState::Done)));
+        .add_systems(Update, (link_animations, play_animation.after(handle_player_updates).before(animate_targets)).run_if(in_state(HandleScenesState::Done)));
     }
 }
\ No newline at end of file
@@ -1,16 +1,18 @@
 use std::{ops::Index, time::Duration};
 
-use bevy::{animation::ActiveAnimation, input::keyboard::{Key, KeyboardInput}, prelude::*, utils::HashMap};
-use crate::{asset_manager, input::GameActions, module_bindings::{entity_component, PlayerAction, PlayerStances}, network::uncb_receiver::{UncbEvent, UncbMessage}, player::{player_bundle::{Player, PlayerEntity}, spawn_player::NonMainPlayer}};
-use super::setup_animations::{AnimationController, GraphAnimations};
+use bevy::{animation::{ActiveAnimation, RepeatAnimation}, input::keyboard::{Key, KeyboardInput}, prelude::*, utils::HashMap};
+use crate::{asset_manager, npc::NpcMark, input::GameActions, module_bindings::{entity_component, PlayerAction, PlayerStances}, network::{entity_map::NetworkEntityMap, uncb_receiver::{UncbEvent, UncbMessage}}, player::{movement::stance_speed_multiplier, player_bundle::{Player, PlayerEntity}, spawn_player::NonMainPlayer}};
+use super::setup_animations::{AnimationController, Animations, GraphAnimations};
 
 
 pub fn play_animation(
     mut commands: Commands,
     mut animation_player_query: Query<&mut AnimationPlayer>,
-    mut player_character_query: Query<(&Player, &AnimationEntityLink, &PlayerEntity, &AnimationController)>,
+    entity_map: Res<NetworkEntityMap>,
+    player_character_query: Query<(&AnimationEntityLink, &PlayerEntity, &AnimationController), Or<(With<Player>, With<NpcMark>)>>,
     // list: Res<AnimationList>,
     graph: Res<GraphAnimations>,
+    animations: Res<Animations>,
     mut event_reader: EventReader<UncbEvent>,
     name: Query<&Name>,
 ) {
@@ -19,76 +21,105 @@ pub fn play_animation(
     //let anim_list = Animations::new(&list);
     for event in event_reader.read() {
         if let UncbMessage::EntityUpdated { new, .. } = &event.message {
-            for (player, animation_entity_link, player_entity, controller) in player_character_query.iter_mut() {
-
-                if player.data.entity_id == new.entity_id {
-
-                    let idle_sword = controller.animations.index_node.get("Idle_Sword_And_Shield").expect("No").clone();
-                    let run_sword = controller.animations.index_node.get("Run_Sword_And_Shield").expect("No").clone();
-
-
-                    let run = controller.animations.index_node.get("Run_Standard").expect("No").clone();
-
-                    let idle = controller.animations.index_node.get("Idle").expect("No").clone();
-
-                    let attack = controller.animations.index_node.get("Attack_Sword_And_Shield_Slash").expect("No").clone();
-
-                    if let Ok(mut animation_player) = animation_player_query.get_mut(animation_entity_link.0) {
-                        let current = match player_entity.data.stance {
-                            PlayerStances::Combat => {
+            let Some(entity) = entity_map.get(new.entity_id) else {
+                continue;
+            };
+            let Ok((animation_entity_link, player_entity, _controller)) = player_character_query.get(entity) else {
+                continue;
+            };
+
+            let Animations {
+                run,
+                idle,
+                idle_sword_shield: idle_sword,
+                run_sword_shield: run_sword,
+                attack_sword: attack,
+                block,
+                dodge,
+                death,
+                idle_precise,
+                run_precise,
+                idle_defensive,
+                run_defensive,
+            } = animations.clone();
+
+            if let Ok(mut animation_player) = animation_player_query.get_mut(animation_entity_link.0) {
+                let current = match player_entity.data.stance {
+                    PlayerStances::Combat => {
                                
-                                if new.moving {
+                        if new.moving {
                                    
-                                    run_sword
-                                } else {
-                                    idle_sword
-                                }
-                            }
-                            PlayerStances::NonCombat => {
-                                if new.moving {
-                                    run
-                                } else {
-                                    idle
-                                }
-                            }
-                            _ => {
-                                if !new.moving {
-                                    idle
-                                } else {
-                                    run
-                                }
-                                
-                            }
-                        };
+                            run_sword
+                        } else {
+                            idle_sword
+                        }
+                    }
+                    PlayerStances::NonCombat => {
+                        if new.moving {
+                            run
+                        } else {
+                            idle
+                        }
+                    }
+                    PlayerStances::Precise => {
+                        if new.moving {
+                            run_precise
+                        } else {
+                            idle_precise
+                        }
+                    }
+                    PlayerStances::Defensive => {
+                        if new.moving {
+                            run_defensive
+                        } else {
+                            idle_defensive
+                        }
+                    }
+                };
                         
 
-                       let action =  match player_entity.data.action {
-                            PlayerAction::Attack => {
+               // The combat stances share clips until the rig has its own per stance, so
+               // runs are played at the stance's speed to match how fast it moves.
+               let speed = if new.moving { stance_speed_multiplier(&player_entity.data.stance) } else { 1.0 };
+
+               // Read from the row itself: the local player's own action is only
+               // set for the frame its key was pressed, long gone by the time the echo arrives.
+               let action = if new.dead {
+                    death
+                } else {
+                    match new.action {
+                        PlayerAction::Attack => {
                                
-                                attack
-                            },
-                            _=> current
-                        };
-
-                       
-                        if !animation_player.is_playing_animation(current) {
-                            
-                            animation_player.stop_all();
-                            animation_player.play(current.clone()).repeat();
-                            //animation_player.play(action);
-                            if action != current {
-                                animation_player.stop_all();
-                                animation_player.play(action);
-                            }
-
-                            
-                            
+                            attack
+                        },
+                        PlayerAction::Block => block,
+                        PlayerAction::Dodge => dodge,
+                        _=> current
+                    }
+                };
 
-                       }
+                if action != current {
+                    // Actions play once over whatever was looping.
+                    if !animation_player.is_playing_animation(action) {
+                        animation_player.stop_all();
+                        animation_player.play(action);
+                    }
+                } else if !animation_player.is_playing_animation(current) {
+                    // Let a running action finish before going back to the loop.
+                    let action_running = animation_player
+                        .playing_animations()
+                        .any(|(_, active)| active.repeat_mode() == RepeatAnimation::Never && !active.is_finished());
+
+                    if !action_running {
+                        animation_player.stop_all();
+                        animation_player.play(current.clone()).repeat();
                     }
-                }
-                commands.entity(animation_entity_link.0).insert(graph.graph.clone());
+               }
+               if let Some(active) = animation_player.animation_mut(current) {
+                    active.set_speed(speed);
+               }
             }
+            commands.entity(animation_entity_link.0).insert(graph.graph.clone());
         }
     }
 }
@@ -15,17 +15,47 @@ pub struct Animations {
     pub idle_sword_shield: AnimationNodeIndex,
     pub run_sword_shield: AnimationNodeIndex,
     pub attack_sword: AnimationNodeIndex,
-
+    pub block: AnimationNodeIndex,
+    pub dodge: AnimationNodeIndex,
+    pub death: AnimationNodeIndex,
+    pub idle_precise: AnimationNodeIndex,
+    pub run_precise: AnimationNodeIndex,
+    pub idle_defensive: AnimationNodeIndex,
+    pub run_defensive: AnimationNodeIndex,
 }
 
 impl Animations {
     pub fn new(node: GraphAnimations) -> Self {
+        let idle = node.index_node.get("Idle").expect("Failed to get idle animation").clone();
+        let idle_sword_shield = node.index_node.get("Idle_Sword_And_Shield").expect("Failed to get Idle_Sword_Shield Animation").clone();
+        let run_sword_shield = node.index_node.get("Run_Sword_And_Shield").expect("Failed to get Run_Sword_Shield Animation").clone();
+        let attack_sword = node.index_node.get("Attack_Sword_And_Shield_Slash").expect("Failed to get Sword Attack animation").clone();
         Self {
             run: node.index_node.get("Run_Standard").expect("Failed to get run animation").clone(),
-            idle: node.index_node.get("Idle").expect("Failed to get idle animation").clone(),
-            idle_sword_shield: node.index_node.get("Idle_Sword_And_Shield").expect("Failed to get Idle_Sword_Shield Animation").clone(),
-            run_sword_shield: node.index_node.get("Run_Sword_And_Shield").expect("Failed to get Run_Sword_Shield Animation").clone(),
-            attack_sword: node.index_node.get("Attack_Sword_And_Shield_Slash").expect("Failed to get Sword Attack animation").clone(),
+            idle,
+            idle_sword_shield,
+            run_sword_shield,
+            attack_sword,
+            // Anything but the combat idle, or blocking in Combat stance would show nothing.
+            block: clip_or(&node, "Block_Sword_And_Shield", attack_sword),
+            dodge: clip_or(&node, "Dodge_Roll", run_sword_shield),
+            death: clip_or(&node, "Death", idle),
+            idle_precise: clip_or(&node, "Idle_Precise", idle_sword_shield),
+            run_precise: clip_or(&node, "Run_Precise", run_sword_shield),
+            idle_defensive: clip_or(&node, "Idle_Defensive", idle_sword_shield),
+            run_defensive: clip_or(&node, "Run_Defensive", run_sword_shield),
+        }
+    }
+}
+
+// Not every clip ships with the character rig yet. Missing ones play `fallback`
+// instead, and say so once when the animations are loaded.
+fn clip_or(node: &GraphAnimations, name: &str, fallback: AnimationNodeIndex) -> AnimationNodeIndex {
+    match node.index_node.get(name) {
+        Some(index) => *index,
+        None => {
+            warn!("Animation {} is missing, using a fallback", name);
+            fallback
         }
     }
 }
@@ -88,7 +118,8 @@ pub fn get_animations(
         graph: graph.clone()
     };
 
+    commands.insert_resource(Animations::new(anim_graph.clone()));
     commands.insert_resource(anim_graph);
-    next_state.set(AssetLoadingState::Spawning);
+    next_state.set(AssetLoadingState::CharacterCreation);
 }
 
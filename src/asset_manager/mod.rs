@@ -26,8 +26,10 @@ pub enum AssetLoadingState {
     #[default]
     Loading,
     LoadingAnimations,
+    CharacterCreation,
     Spawning,
-    _Done,
+    // Back in the world after being turned away, see character_creation/mod.rs.
+    Done,
 }
 
 #[derive(AssetCollection, Resource)]
@@ -42,6 +44,6 @@ pub struct GameAssets {
     pub terrain_files: HashMap<String, Handle<Gltf>>,
 
     #[asset(paths("models\\axe.glb"), collection(typed, mapped))]
-    pub _weapons: HashMap<String, Handle<Gltf>>,
+    pub weapons: HashMap<String, Handle<Gltf>>,
     
 }
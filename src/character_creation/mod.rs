@@ -0,0 +1,325 @@
+use bevy::{input::{keyboard::{Key, KeyboardInput}, ButtonState}, prelude::*};
+use spacetimedb_sdk::identity::identity;
+
+use crate::{
+    asset_manager::AssetLoadingState,
+    module_bindings::{create_character, create_player, Character, ReducerEvent},
+    network::{
+        player_connected::PlayerDataResource,
+        reconnect::ConnectionState,
+        uncb_receiver::{UncbEvent, UncbMessage},
+        SubscriptionApplied,
+    },
+    player::HandleScenesState,
+};
+
+const MAX_NAME_LEN: usize = 16;
+const BUTTON_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.9);
+
+pub struct CharacterCreationPlugin;
+impl Plugin for CharacterCreationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CharacterCreation>()
+            .add_systems(Update, (
+                check_existing_character,
+                name_input,
+                handle_creation_result,
+                join_button,
+                await_local_player,
+            ).chain().run_if(in_state(AssetLoadingState::CharacterCreation)))
+            // Kicks, bans and rejoins after a reconnect happen in the world.
+            .add_systems(Update, handle_turned_away)
+            .add_systems(OnExit(AssetLoadingState::CharacterCreation), despawn_creation_screen);
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CreationPhase {
+    #[default]
+    WaitingForSubscription,
+    Editing,
+    Submitted,
+    Joining,
+    // `create_player` failed, e.g. the server is full, or we were kicked out of
+    // the world. Waits for `JoinButton`.
+    Rejected,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct CharacterCreation {
+    pub phase: CreationPhase,
+    pub name: String,
+    pub error: Option<String>,
+}
+
+#[derive(Component)]
+struct CreationScreen;
+
+#[derive(Component)]
+struct NameText;
+
+#[derive(Component)]
+struct ErrorText;
+
+#[derive(Component)]
+struct JoinButton;
+
+// Returning players skip the screen, everyone else gets asked for a name.
+fn check_existing_character(
+    mut commands: Commands,
+    mut creation: ResMut<CharacterCreation>,
+    subscribed: Res<SubscriptionApplied>,
+) {
+    if creation.phase != CreationPhase::WaitingForSubscription || !subscribed.0 {
+        return;
+    }
+
+    // Known before the subscription applies, but don't take that for granted.
+    let Ok(identity) = identity() else {
+        return;
+    };
+    if let Some(character) = Character::find_by_owner_id(identity) {
+        info!("Welcome back, {}", character.username);
+        create_player();
+        creation.phase = CreationPhase::Joining;
+    } else {
+        spawn_creation_screen(&mut commands);
+        creation.phase = CreationPhase::Editing;
+    }
+}
+
+fn spawn_creation_screen(commands: &mut Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.8).into(),
+                ..default()
+            },
+            CreationScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Name your character and press Enter",
+                TextStyle { font_size: 28.0, ..default() },
+            ));
+            parent.spawn((
+                TextBundle::from_section("_", TextStyle { font_size: 36.0, ..default() }),
+                NameText,
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle { font_size: 20.0, color: Color::srgb(0.9, 0.3, 0.3), ..default() },
+                ),
+                ErrorText,
+            ));
+        });
+}
+
+// Tells the player why they aren't in the world and lets them try to get back in.
+fn spawn_join_screen(commands: &mut Commands, title: &str, reason: &str) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.8).into(),
+                ..default()
+            },
+            CreationScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(title, TextStyle { font_size: 28.0, ..default() }));
+            parent.spawn((
+                TextBundle::from_section(
+                    reason,
+                    TextStyle { font_size: 20.0, color: Color::srgb(0.9, 0.3, 0.3), ..default() },
+                ),
+                ErrorText,
+            ));
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style { padding: UiRect::all(Val::Px(8.0)), ..default() },
+                        background_color: BUTTON_COLOR.into(),
+                        ..default()
+                    },
+                    JoinButton,
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section("Try again", TextStyle { font_size: 20.0, ..default() }));
+                });
+        });
+}
+
+fn despawn_creation_screen(mut commands: Commands, screen_q: Query<Entity, With<CreationScreen>>) {
+    for entity in screen_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn name_input(
+    mut creation: ResMut<CharacterCreation>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut name_q: Query<&mut Text, With<NameText>>,
+) {
+    if creation.phase != CreationPhase::Editing {
+        keyboard_events.clear();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => {
+                if creation.name.chars().count() < MAX_NAME_LEN {
+                    creation.name.push_str(chars);
+                }
+            }
+            Key::Backspace => {
+                creation.name.pop();
+            }
+            Key::Enter if !creation.name.is_empty() => {
+                // Validation happens on the server, errors come back through the reducer status.
+                create_character(creation.name.clone());
+                creation.phase = CreationPhase::Submitted;
+            }
+            _ => {}
+        }
+    }
+
+    if let Ok(mut text) = name_q.get_single_mut() {
+        text.sections[0].value = format!("{}_", creation.name);
+    }
+}
+
+fn handle_creation_result(
+    mut creation: ResMut<CharacterCreation>,
+    mut event_reader: EventReader<UncbEvent>,
+    mut error_q: Query<&mut Text, With<ErrorText>>,
+) {
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::CharacterCreated => {
+                create_player();
+                creation.error = None;
+                creation.phase = CreationPhase::Joining;
+            }
+            UncbMessage::CharacterRejected { reason } => {
+                warn!("Character rejected: {}", reason);
+                creation.error = Some(reason.clone());
+                creation.phase = CreationPhase::Editing;
+            }
+            _ => {}
+        }
+    }
+
+    if let Ok(mut text) = error_q.get_single_mut() {
+        text.sections[0].value = creation.error.clone().unwrap_or_default();
+    }
+}
+
+// Replaces whatever screen is up with the reason, and takes us back here if we
+// were already in the world. `player_removed_data` despawns our player.
+fn handle_turned_away(
+    mut commands: Commands,
+    mut creation: ResMut<CharacterCreation>,
+    mut event_reader: EventReader<UncbEvent>,
+    screen_q: Query<Entity, With<CreationScreen>>,
+    connection_state: Res<State<ConnectionState>>,
+    loading_state: Res<State<AssetLoadingState>>,
+    mut next_state: ResMut<NextState<AssetLoadingState>>,
+) {
+    for event in event_reader.read() {
+        let (title, reason) = match &event.message {
+            UncbMessage::JoinRejected { reason } => ("Couldn't enter the world", reason.clone()),
+            // Only a kick or ban removes us while connected, the server drops our
+            // player on every disconnect.
+            UncbMessage::PlayerRemoved { data, event }
+                if *connection_state.get() == ConnectionState::Connected
+                    && identity().is_ok_and(|own| own == data.owner_id) =>
+            {
+                match event {
+                    Some(ReducerEvent::BanPlayer(args)) if args.reason.is_empty() => {
+                        ("You were banned", "An admin banned you from this server".to_string())
+                    }
+                    Some(ReducerEvent::BanPlayer(args)) => ("You were banned", args.reason.clone()),
+                    Some(ReducerEvent::KickPlayer(_)) => {
+                        ("You were kicked", "An admin kicked you, you can rejoin in a minute".to_string())
+                    }
+                    _ => ("Removed from the world", "The server took your player out of the world".to_string()),
+                }
+            }
+            _ => continue,
+        };
+        warn!("{}: {}", title, reason);
+        for entity in screen_q.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        spawn_join_screen(&mut commands, title, &reason);
+        creation.error = Some(reason);
+        creation.phase = CreationPhase::Rejected;
+        if *loading_state.get() != AssetLoadingState::CharacterCreation {
+            next_state.set(AssetLoadingState::CharacterCreation);
+        }
+    }
+}
+
+fn join_button(
+    mut creation: ResMut<CharacterCreation>,
+    button_q: Query<&Interaction, (Changed<Interaction>, With<JoinButton>)>,
+) {
+    if creation.phase != CreationPhase::Rejected {
+        return;
+    }
+    if button_q.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        create_player();
+        creation.error = None;
+        creation.phase = CreationPhase::Joining;
+    }
+}
+
+// Spawning needs our own PlayerComponent in the PlayerDataResource, so only move
+// on once the server has inserted it.
+fn await_local_player(
+    creation: Res<CharacterCreation>,
+    player_data: Res<PlayerDataResource>,
+    scenes_state: Res<State<HandleScenesState>>,
+    mut next_state: ResMut<NextState<AssetLoadingState>>,
+) {
+    if creation.phase != CreationPhase::Joining {
+        return;
+    }
+
+    let Ok(identity) = identity() else {
+        return;
+    };
+    if player_data.player_vec.iter().any(|p| p.data.owner_id == identity) {
+        // Once the world is up `spawn_new_players` spawns us like any other player.
+        if *scenes_state.get() == HandleScenesState::Done {
+            next_state.set(AssetLoadingState::Done);
+        } else {
+            next_state.set(AssetLoadingState::Spawning);
+        }
+    }
+}
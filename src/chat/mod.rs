@@ -0,0 +1,308 @@
+use std::collections::{HashSet, VecDeque};
+
+use bevy::{
+    input::{keyboard::{Key, KeyboardInput}, ButtonState},
+    prelude::*,
+};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    input::GameActions,
+    module_bindings::{
+        ban_player, grant_admin, kick_player, mute_player, revoke_admin, send_chat, set_message_of_the_day,
+        set_player_health, set_player_stance, teleport_player, unban_player, unmute_player, ChatChannel,
+        ChatMessage, ChatScope, PlayerStances, StdbVector3,
+    },
+    network::uncb_receiver::{UncbEvent, UncbMessage},
+    player::HandleScenesState,
+};
+
+// Must match `MAX_CHAT_LEN` in the server module, longer input isn't accepted.
+const MAX_INPUT_LEN: usize = 256;
+const MAX_LOG_LINES: usize = 50;
+const VISIBLE_LINES: usize = 10;
+const CHAT_PANEL_WIDTH: f32 = 420.0;
+// For `/mute name` without a duration.
+const DEFAULT_MUTE_MINUTES: u64 = 10;
+// Shown when an admin command gets the wrong arguments.
+const ADMIN_USAGE: &[(&str, &str)] = &[
+    ("kick", "/kick name"),
+    ("ban", "/ban name [reason]"),
+    ("unban", "/unban name"),
+    ("mute", "/mute name [minutes]"),
+    ("unmute", "/unmute name"),
+    ("tp", "/tp name x y z"),
+    ("stance", "/stance name noncombat|combat|precise|defensive"),
+    ("health", "/health name amount"),
+    ("admin", "/admin name"),
+    ("unadmin", "/unadmin name"),
+];
+
+pub struct ChatPlugin;
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ChatState>()
+            .add_systems(OnEnter(HandleScenesState::Done), spawn_chat_panel)
+            .add_systems(Update, (
+                chat_input,
+                suspend_game_actions,
+                handle_chat_events,
+                update_chat_panel,
+            ).chain().run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+pub struct ChatLine {
+    pub text: String,
+    pub color: Color,
+}
+
+#[derive(Resource, Default)]
+pub struct ChatState {
+    // While typing, keyboard input goes to the chat and `GameActions` are suspended.
+    pub typing: bool,
+    pub input: String,
+    pub lines: VecDeque<ChatLine>,
+    // Rows can arrive again when subscriptions are renewed, see network/interest.rs.
+    seen: HashSet<u64>,
+}
+
+impl ChatState {
+    pub fn push_line(&mut self, text: String, color: Color) {
+        if self.lines.len() == MAX_LOG_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(ChatLine { text, color });
+    }
+
+    pub fn system_line(&mut self, text: impl Into<String>) {
+        self.push_line(text.into(), Color::srgb(0.9, 0.4, 0.4));
+    }
+}
+
+#[derive(Component)]
+pub struct ChatLog;
+
+#[derive(Component)]
+pub struct ChatInput;
+
+fn spawn_chat_panel(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(12.0),
+                bottom: Val::Px(12.0),
+                width: Val::Px(CHAT_PANEL_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.35).into(),
+            ..default()
+        })
+        .with_children(|panel| {
+            panel.spawn((TextBundle::default(), ChatLog));
+            panel.spawn((
+                TextBundle::from_section("", TextStyle { font_size: 16.0, ..default() }),
+                ChatInput,
+            ));
+        });
+}
+
+// Enter opens the input and sends, Escape cancels. Everything typed in between
+// goes to the chat.
+pub fn chat_input(mut chat: ResMut<ChatState>, mut keyboard_events: EventReader<KeyboardInput>) {
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if !chat.typing {
+            if event.logical_key == Key::Enter {
+                chat.typing = true;
+            }
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let input = std::mem::take(&mut chat.input);
+                chat.typing = false;
+                if let Err(error) = submit_chat(&input) {
+                    chat.system_line(error);
+                }
+            }
+            Key::Escape => {
+                chat.input.clear();
+                chat.typing = false;
+            }
+            Key::Backspace => {
+                chat.input.pop();
+            }
+            Key::Space => push_input(&mut chat.input, " "),
+            Key::Character(chars) => push_input(&mut chat.input, chars),
+            _ => {}
+        }
+    }
+}
+
+fn push_input(input: &mut String, chars: &str) {
+    if input.chars().count() + chars.chars().count() <= MAX_INPUT_LEN {
+        input.push_str(chars);
+    }
+}
+
+// Plain text goes to say. `/s`, `/z`, `/g` pick the channel for one message and
+// `/w name` whispers. Everything else is an admin command, the server checks
+// whether we're allowed and failures come back as `CommandRejected`.
+pub fn submit_chat(input: &str) -> Result<(), String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(());
+    }
+    let Some(command) = input.strip_prefix('/') else {
+        return send_to(ChatScope::Say, input, None);
+    };
+
+    let (name, rest) = command.split_once(' ').unwrap_or((command, ""));
+    match name {
+        "s" | "say" => send_to(ChatScope::Say, rest, None),
+        "z" | "zone" => send_to(ChatScope::Zone, rest, None),
+        "g" | "global" => send_to(ChatScope::Global, rest, None),
+        "w" | "whisper" => {
+            let (target, text) = rest.trim().split_once(' ').ok_or("Usage: /w name message")?;
+            send_to(ChatScope::Whisper, text, Some(target.to_string()))
+        }
+        _ => admin_command(name, rest),
+    }
+}
+
+fn admin_command(name: &str, rest: &str) -> Result<(), String> {
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    match (name, args.as_slice()) {
+        ("kick", [target]) => kick_player(target.to_string()),
+        ("ban", [target, reason @ ..]) => ban_player(target.to_string(), reason.join(" ")),
+        ("unban", [target]) => unban_player(target.to_string()),
+        ("mute", [target]) => mute_player(target.to_string(), DEFAULT_MUTE_MINUTES),
+        ("mute", [target, minutes]) => mute_player(target.to_string(), parse_arg(minutes)?),
+        ("unmute", [target]) => unmute_player(target.to_string()),
+        ("tp", [target, x, y, z]) => teleport_player(
+            target.to_string(),
+            StdbVector3 { x: parse_arg(x)?, y: parse_arg(y)?, z: parse_arg(z)? },
+        ),
+        ("stance", [target, stance]) => set_player_stance(target.to_string(), parse_stance(stance)?),
+        ("health", [target, health]) => set_player_health(target.to_string(), parse_arg(health)?),
+        ("admin", [target]) => grant_admin(target.to_string()),
+        ("unadmin", [target]) => revoke_admin(target.to_string()),
+        ("motd", _) => set_message_of_the_day(rest.trim().to_string()),
+        _ => {
+            return match ADMIN_USAGE.iter().find(|(command, _)| *command == name) {
+                Some((_, usage)) => Err(format!("Usage: {}", usage)),
+                None => Err(format!("Unknown command /{}", name)),
+            };
+        }
+    }
+    Ok(())
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("Invalid number: {}", arg))
+}
+
+fn parse_stance(stance: &str) -> Result<PlayerStances, String> {
+    match stance.to_ascii_lowercase().as_str() {
+        "noncombat" => Ok(PlayerStances::NonCombat),
+        "combat" => Ok(PlayerStances::Combat),
+        "precise" => Ok(PlayerStances::Precise),
+        "defensive" => Ok(PlayerStances::Defensive),
+        _ => Err(format!("Unknown stance: {}", stance)),
+    }
+}
+
+fn send_to(scope: ChatScope, text: &str, whisper_to: Option<String>) -> Result<(), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(());
+    }
+    let channel = ChatChannel::iter()
+        .find(|channel| channel.scope == scope)
+        .ok_or_else(|| format!("No {:?} channel on this server", scope))?;
+    // Length, rate limits and who hears it are checked by the server.
+    send_chat(channel.channel_id, text.to_string(), whisper_to);
+    Ok(())
+}
+
+pub fn suspend_game_actions(chat: Res<ChatState>, mut action_q: Query<&mut ActionState<GameActions>>) {
+    for mut action_state in action_q.iter_mut() {
+        if chat.typing {
+            action_state.disable();
+        } else {
+            action_state.enable();
+        }
+    }
+}
+
+pub fn handle_chat_events(mut chat: ResMut<ChatState>, mut event_reader: EventReader<UncbEvent>) {
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::ChatMessageInserted { data } => {
+                if chat.seen.insert(data.message_id) {
+                    let (text, color) = format_message(data);
+                    chat.push_line(text, color);
+                }
+            }
+            UncbMessage::ChatRejected { reason } | UncbMessage::CommandRejected { reason } => {
+                chat.system_line(reason.clone())
+            }
+            _ => {}
+        }
+    }
+}
+
+fn format_message(message: &ChatMessage) -> (String, Color) {
+    let channel = ChatChannel::find_by_channel_id(message.channel_id);
+    let name = channel.as_ref().map_or("?", |channel| channel.name.as_str());
+    let color = match channel.map(|channel| channel.scope) {
+        Some(ChatScope::Zone) => Color::srgb(0.6, 0.9, 0.6),
+        Some(ChatScope::Global) => Color::srgb(1.0, 0.75, 0.4),
+        Some(ChatScope::Whisper) => Color::srgb(0.95, 0.6, 0.9),
+        _ => Color::WHITE,
+    };
+    (format!("[{}] {}: {}", name, message.sender_name, message.text), color)
+}
+
+pub fn update_chat_panel(
+    chat: Res<ChatState>,
+    mut log_q: Query<&mut Text, (With<ChatLog>, Without<ChatInput>)>,
+    mut input_q: Query<&mut Text, (With<ChatInput>, Without<ChatLog>)>,
+) {
+    if !chat.is_changed() {
+        return;
+    }
+
+    if let Ok(mut log) = log_q.get_single_mut() {
+        let skip = chat.lines.len().saturating_sub(VISIBLE_LINES);
+        log.sections = chat
+            .lines
+            .iter()
+            .skip(skip)
+            .map(|line| {
+                TextSection::new(
+                    format!("{}\n", line.text),
+                    TextStyle { font_size: 16.0, color: line.color, ..default() },
+                )
+            })
+            .collect();
+    }
+
+    if let Ok(mut input) = input_q.get_single_mut() {
+        input.sections[0].value = if chat.typing {
+            format!("> {}_", chat.input)
+        } else {
+            "Press Enter to chat".to_string()
+        };
+        input.sections[0].style.color = if chat.typing { Color::WHITE } else { Color::srgb(0.6, 0.6, 0.6) };
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::identity::identity;
+
+use crate::{
+    module_bindings::CombatEventKind,
+    network::{entity_map::NetworkEntityMap, uncb_receiver::{UncbEvent, UncbMessage}},
+    player::{player_bundle::Player, HandleScenesState},
+};
+
+const DAMAGE_NUMBER_LIFETIME: f32 = 1.0;
+const DAMAGE_NUMBER_RISE: f32 = 0.5;
+const HIT_REACTION_TIME: f32 = 0.2;
+
+pub struct CombatPlugin;
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (handle_combat_events, update_damage_numbers, update_hit_reactions)
+                .run_if(in_state(HandleScenesState::Done)),
+        );
+    }
+}
+
+// Floating text showing the damage of a hit, anchored to a point in the world.
+#[derive(Component)]
+pub struct DamageNumber {
+    pub world_position: Vec3,
+    pub timer: Timer,
+}
+
+// Short flinch played on an entity that just got hit.
+#[derive(Component)]
+pub struct HitReaction {
+    pub timer: Timer,
+}
+
+pub fn handle_combat_events(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    entity_map: Res<NetworkEntityMap>,
+    target_q: Query<(Option<&Player>, &GlobalTransform)>,
+) {
+    let local_identity = identity().ok();
+
+    for event in event_reader.read() {
+        let UncbMessage::CombatEventInserted { data } = &event.message else {
+            continue;
+        };
+        let Some(entity) = entity_map.get(data.target_id) else {
+            continue;
+        };
+        let Ok((player, transform)) = target_q.get(entity) else {
+            continue;
+        };
+
+        let is_local = player.is_some_and(|player| Some(player.data.owner_id.clone()) == local_identity);
+        let color = match (&data.kind, is_local) {
+            (CombatEventKind::Killed, _) => Color::srgb(1.0, 0.85, 0.2),
+            (CombatEventKind::Blocked | CombatEventKind::Dodged, _) => Color::srgb(0.6, 0.7, 0.9),
+            (_, true) => Color::srgb(0.9, 0.2, 0.2),
+            (_, false) => Color::WHITE,
+        };
+
+        let label = match data.kind {
+            CombatEventKind::Blocked => format!("Blocked {:.0}", data.damage),
+            CombatEventKind::Dodged => "Dodged".to_string(),
+            _ => format!("{:.0}", data.damage),
+        };
+
+        commands.spawn((
+            TextBundle::from_section(
+                label,
+                TextStyle { font_size: 24.0, color, ..default() },
+            )
+            .with_style(Style { position_type: PositionType::Absolute, ..default() }),
+            DamageNumber {
+                world_position: transform.translation() + Vec3::Y * 0.5,
+                timer: Timer::from_seconds(DAMAGE_NUMBER_LIFETIME, TimerMode::Once),
+            },
+        ));
+        if data.kind != CombatEventKind::Dodged {
+            commands.entity(entity).insert(HitReaction {
+                timer: Timer::from_seconds(HIT_REACTION_TIME, TimerMode::Once),
+            });
+        }
+    }
+}
+
+pub fn update_damage_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut number_q: Query<(Entity, &mut DamageNumber, &mut Style, &mut Text)>,
+) {
+    let Ok((camera, cam_transform)) = cam_q.get_single() else {
+        return;
+    };
+
+    for (entity, mut number, mut style, mut text) in number_q.iter_mut() {
+        number.timer.tick(time.delta());
+        if number.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let progress = number.timer.fraction();
+        let position = number.world_position + Vec3::Y * DAMAGE_NUMBER_RISE * progress;
+        if let Some(screen) = camera.world_to_viewport(cam_transform, position) {
+            style.left = Val::Px(screen.x);
+            style.top = Val::Px(screen.y);
+        }
+        text.sections[0].style.color.set_alpha(1.0 - progress);
+    }
+}
+
+pub fn update_hit_reactions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut reaction_q: Query<(Entity, &mut HitReaction, &mut Transform)>,
+) {
+    for (entity, mut reaction, mut transform) in reaction_q.iter_mut() {
+        reaction.timer.tick(time.delta());
+        if reaction.timer.finished() {
+            transform.scale = Vec3::ONE;
+            commands.entity(entity).remove::<HitReaction>();
+        } else {
+            // Squash down and spring back over the reaction time.
+            let squash = 0.1 * reaction.timer.fraction_remaining();
+            transform.scale = Vec3::new(1.0 + squash, 1.0 - squash, 1.0 + squash);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::{collections::HashMap, env, fs, path::Path};
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+const DEFAULT_CONFIG_FILE: &str = "dagalok.toml";
+const ENV_PREFIX: &str = "DAGALOK_";
+
+/// Where to connect and which identity to use. Built from, in increasing priority:
+/// the defaults below, `dagalok.toml`, the profile's section of that file,
+/// `DAGALOK_*` environment variables and command line flags.
+#[derive(Resource, Debug, Clone)]
+pub struct ClientConfig {
+    pub uri: String,
+    pub db_name: String,
+    pub creds_dir: String,
+    pub profile: Option<String>,
+    /// Don't load stored credentials, every run gets a fresh identity.
+    pub debug_mode: bool,
+    /// How many times per second the local player's state is sent, see network/send_scheduler.rs.
+    pub send_rate: f32,
+    /// Seconds remote players are rendered behind the latest update, see player/interpolation.rs.
+    pub interpolation_delay: f32,
+    /// Seconds a remote player takes to fade out after leaving, 0 despawns them at once.
+    pub departure_fade: f32,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            uri: "http://127.0.0.1:3000".to_string(),
+            db_name: "dagalok".to_string(),
+            creds_dir: ".dagalok".to_string(),
+            profile: None,
+            debug_mode: true,
+            send_rate: 20.0,
+            interpolation_delay: 0.1,
+            departure_fade: 0.5,
+        }
+    }
+}
+
+// One source of settings, anything left out falls through to the layer below.
+#[derive(Deserialize, Default, Debug, Clone)]
+struct ConfigLayer {
+    uri: Option<String>,
+    db_name: Option<String>,
+    creds_dir: Option<String>,
+    profile: Option<String>,
+    debug_mode: Option<bool>,
+    send_rate: Option<f32>,
+    interpolation_delay: Option<f32>,
+    departure_fade: Option<f32>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+struct ConfigFile {
+    #[serde(flatten)]
+    base: ConfigLayer,
+    // e.g. `[profiles.staging]`, applied on top of the base when that profile is selected.
+    #[serde(default)]
+    profiles: HashMap<String, ConfigLayer>,
+}
+
+const USAGE: &str = "Usage: client [--config FILE] [--profile NAME] [--uri URI] [--db NAME] [--creds-dir DIR] [--debug | --no-debug] [--send-rate HZ] [--interpolation-delay SECONDS] [--departure-fade SECONDS]";
+
+impl ClientConfig {
+    pub fn load() -> Result<Self, String> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        let cli = parse_args(&args)?;
+        let env_layer = env_layer()?;
+
+        let path = cli
+            .config_path
+            .clone()
+            .or_else(|| env::var(format!("{}CONFIG", ENV_PREFIX)).ok());
+        let file = match &path {
+            Some(path) => read_file(Path::new(path))?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => read_file(Path::new(DEFAULT_CONFIG_FILE))?,
+            None => ConfigFile::default(),
+        };
+
+        let profile = cli
+            .layer
+            .profile
+            .clone()
+            .or_else(|| env_layer.profile.clone())
+            .or_else(|| file.base.profile.clone());
+
+        let mut config = ClientConfig::default();
+        config.apply(&file.base);
+        if let Some(profile) = &profile {
+            match file.profiles.get(profile) {
+                Some(layer) => config.apply(layer),
+                None if path.is_some() || !file.profiles.is_empty() => {
+                    log::warn!("No [profiles.{}] section in the config file, using the base settings", profile);
+                }
+                None => {}
+            }
+        }
+        config.apply(&env_layer);
+        config.apply(&cli.layer);
+        config.profile = profile;
+
+        if !(config.send_rate.is_finite() && config.send_rate > 0.0) {
+            return Err(format!("send_rate must be a positive number, got {}", config.send_rate));
+        }
+        if !(config.interpolation_delay.is_finite() && config.interpolation_delay >= 0.0) {
+            return Err(format!("interpolation_delay must be zero or more seconds, got {}", config.interpolation_delay));
+        }
+        if !(config.departure_fade.is_finite() && config.departure_fade >= 0.0) {
+            return Err(format!("departure_fade must be zero or more seconds, got {}", config.departure_fade));
+        }
+
+        Ok(config)
+    }
+
+    fn apply(&mut self, layer: &ConfigLayer) {
+        if let Some(uri) = &layer.uri {
+            self.uri = uri.clone();
+        }
+        if let Some(db_name) = &layer.db_name {
+            self.db_name = db_name.clone();
+        }
+        if let Some(creds_dir) = &layer.creds_dir {
+            self.creds_dir = creds_dir.clone();
+        }
+        if let Some(debug_mode) = layer.debug_mode {
+            self.debug_mode = debug_mode;
+        }
+        if let Some(send_rate) = layer.send_rate {
+            self.send_rate = send_rate;
+        }
+        if let Some(interpolation_delay) = layer.interpolation_delay {
+            self.interpolation_delay = interpolation_delay;
+        }
+        if let Some(departure_fade) = layer.departure_fade {
+            self.departure_fade = departure_fade;
+        }
+    }
+
+    /// Credentials live in a directory per profile, so several clients on one
+    /// machine keep distinct identities.
+    pub fn credentials_dir(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("{}/{}", self.creds_dir, profile),
+            None => self.creds_dir.clone(),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<ConfigFile, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    toml::from_str(&contents).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+fn env_layer() -> Result<ConfigLayer, String> {
+    let var = |name: &str| env::var(format!("{}{}", ENV_PREFIX, name)).ok();
+    let debug_mode = match var("DEBUG") {
+        Some(value) => Some(parse_bool(&value).ok_or_else(|| format!("{}DEBUG must be true or false, got {:?}", ENV_PREFIX, value))?),
+        None => None,
+    };
+    let send_rate = match var("SEND_RATE") {
+        Some(value) => Some(value.parse().map_err(|_| format!("{}SEND_RATE must be a number, got {:?}", ENV_PREFIX, value))?),
+        None => None,
+    };
+    let interpolation_delay = match var("INTERPOLATION_DELAY") {
+        Some(value) => Some(value.parse().map_err(|_| format!("{}INTERPOLATION_DELAY must be a number, got {:?}", ENV_PREFIX, value))?),
+        None => None,
+    };
+    let departure_fade = match var("DEPARTURE_FADE") {
+        Some(value) => Some(value.parse().map_err(|_| format!("{}DEPARTURE_FADE must be a number, got {:?}", ENV_PREFIX, value))?),
+        None => None,
+    };
+
+    Ok(ConfigLayer {
+        uri: var("URI"),
+        db_name: var("DB_NAME"),
+        creds_dir: var("CREDS_DIR"),
+        profile: var("PROFILE"),
+        debug_mode,
+        send_rate,
+        interpolation_delay,
+        departure_fade,
+    })
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct CliArgs {
+    config_path: Option<String>,
+    layer: ConfigLayer,
+}
+
+fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut cli = CliArgs::default();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        // Accept both `--flag value` and `--flag=value`.
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next().cloned())
+                .ok_or_else(|| format!("{} needs a value\n{}", flag, USAGE))
+        };
+
+        match flag {
+            "--config" => cli.config_path = Some(value()?),
+            "--profile" => cli.layer.profile = Some(value()?),
+            "--uri" => cli.layer.uri = Some(value()?),
+            "--db" => cli.layer.db_name = Some(value()?),
+            "--creds-dir" => cli.layer.creds_dir = Some(value()?),
+            "--debug" => cli.layer.debug_mode = Some(true),
+            "--no-debug" => cli.layer.debug_mode = Some(false),
+            "--send-rate" => {
+                let rate = value()?;
+                cli.layer.send_rate = Some(rate.parse().map_err(|_| format!("--send-rate must be a number, got {}", rate))?);
+            }
+            "--interpolation-delay" => {
+                let delay = value()?;
+                cli.layer.interpolation_delay = Some(delay.parse().map_err(|_| format!("--interpolation-delay must be a number, got {}", delay))?);
+            }
+            "--departure-fade" => {
+                let fade = value()?;
+                cli.layer.departure_fade = Some(fade.parse().map_err(|_| format!("--departure-fade must be a number, got {}", fade))?);
+            }
+            _ => return Err(format!("Unknown argument {}\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(cli)
+}
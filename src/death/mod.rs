@@ -0,0 +1,179 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::{
+    module_bindings::{respawn, Death},
+    network::uncb_receiver::{UncbEvent, UncbMessage},
+    player::{
+        player_bundle::{PlayerEntity, PlayerMark},
+        HandleScenesState,
+    },
+};
+
+const BUTTON_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.9);
+const DISABLED_BUTTON_COLOR: Color = Color::srgba(0.08, 0.08, 0.08, 0.9);
+
+pub struct DeathPlugin;
+impl Plugin for DeathPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<DeathScreen>()
+            .add_systems(OnEnter(HandleScenesState::Done), setup_death_screen)
+            .add_systems(Update, (
+                update_death_screen,
+                respawn_button,
+                show_respawn_errors,
+            ).chain().run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+// Counts down to the server's `Death::respawn_at`. Started from what's left of
+// it when the death arrives, which matters when a character that logged out dead
+// rejoins. The server checks again, so a client clock that's off only skews the label.
+#[derive(Resource, Default)]
+pub struct DeathScreen {
+    pub respawn_timer: Option<Timer>,
+}
+
+#[derive(Component)]
+struct DeathPanel;
+
+#[derive(Component)]
+struct CountdownText;
+
+#[derive(Component)]
+struct RespawnButton;
+
+#[derive(Component)]
+struct RespawnErrorText;
+
+pub fn setup_death_screen(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.2, 0.0, 0.0, 0.5).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DeathPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn(TextBundle::from_section("You died", TextStyle { font_size: 48.0, ..default() }));
+            panel
+                .spawn((
+                    ButtonBundle {
+                        style: Style { padding: UiRect::all(Val::Px(8.0)), ..default() },
+                        background_color: DISABLED_BUTTON_COLOR.into(),
+                        ..default()
+                    },
+                    RespawnButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        TextBundle::from_section("", TextStyle { font_size: 20.0, ..default() }),
+                        CountdownText,
+                    ));
+                });
+            panel.spawn((
+                TextBundle::from_section("", TextStyle { font_size: 16.0, color: Color::srgb(1.0, 0.4, 0.4), ..default() }),
+                RespawnErrorText,
+            ));
+        });
+}
+
+// Shows the screen while our entity is dead and counts down until the respawn
+// button works. The entity's `dead` flag comes from `reconcile_local_player`.
+fn update_death_screen(
+    time: Res<Time>,
+    mut screen: ResMut<DeathScreen>,
+    player_q: Query<&PlayerEntity, With<PlayerMark>>,
+    mut panel_q: Query<&mut Visibility, With<DeathPanel>>,
+    mut button_q: Query<&mut BackgroundColor, With<RespawnButton>>,
+    mut text_q: Query<&mut Text, With<CountdownText>>,
+    mut error_q: Query<&mut Text, (With<RespawnErrorText>, Without<CountdownText>)>,
+) {
+    let Ok(player_entity) = player_q.get_single() else {
+        return;
+    };
+
+    match (player_entity.data.dead, screen.respawn_timer.is_some()) {
+        (true, false) => {
+            for mut text in error_q.iter_mut() {
+                text.sections[0].value.clear();
+            }
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_micros() as u64);
+            let delay = Death::find_by_entity_id(player_entity.data.entity_id)
+                .map_or(0, |death| death.respawn_at.saturating_sub(now));
+            screen.respawn_timer = Some(Timer::new(Duration::from_micros(delay), TimerMode::Once));
+            for mut visibility in panel_q.iter_mut() {
+                *visibility = Visibility::Visible;
+            }
+        }
+        (false, true) => {
+            screen.respawn_timer = None;
+            for mut visibility in panel_q.iter_mut() {
+                *visibility = Visibility::Hidden;
+            }
+            return;
+        }
+        (false, false) => return,
+        (true, true) => {}
+    }
+
+    let Some(timer) = screen.respawn_timer.as_mut() else {
+        return;
+    };
+    timer.tick(time.delta());
+    let label = if timer.finished() {
+        "Respawn".to_string()
+    } else {
+        format!("Respawn in {}", timer.remaining_secs().ceil() as u32)
+    };
+    for mut text in text_q.iter_mut() {
+        text.sections[0].value.clone_from(&label);
+    }
+    let color = if timer.finished() { BUTTON_COLOR } else { DISABLED_BUTTON_COLOR };
+    for mut background in button_q.iter_mut() {
+        *background = color.into();
+    }
+}
+
+// The server checks the timer again and picks the spawn point. If it says no,
+// the button stays enabled and `show_respawn_errors` tells the player why.
+fn respawn_button(
+    screen: Res<DeathScreen>,
+    button_q: Query<&Interaction, (Changed<Interaction>, With<RespawnButton>)>,
+) {
+    let ready = screen.respawn_timer.as_ref().is_some_and(Timer::finished);
+    for interaction in button_q.iter() {
+        if *interaction == Interaction::Pressed && ready {
+            respawn();
+        }
+    }
+}
+
+fn show_respawn_errors(
+    mut event_reader: EventReader<UncbEvent>,
+    mut error_q: Query<&mut Text, With<RespawnErrorText>>,
+) {
+    for event in event_reader.read() {
+        let UncbMessage::RespawnRejected { reason } = &event.message else {
+            continue;
+        };
+        warn!("Respawn failed: {}", reason);
+        for mut text in error_q.iter_mut() {
+            text.sections[0].value.clone_from(reason);
+        }
+    }
+}
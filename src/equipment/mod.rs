@@ -0,0 +1,146 @@
+use bevy::{prelude::*, utils::HashMap};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::identity::{identity, Identity};
+
+use crate::{
+    asset_manager::GameAssets,
+    input::GameActions,
+    module_bindings::{equip_item, unequip_item, EquipSlot, Equipment, InventorySlot, ItemDef, ItemKind},
+    network::uncb_receiver::{UncbEvent, UncbMessage},
+    player::{player_bundle::{Player, PlayerMark}, HandleScenesState},
+};
+
+// Bone names the right hand goes by in the rigs we use, checked in order.
+const HAND_BONES: &[&str] = &["mixamorig:RightHand", "RightHand", "hand.R", "Hand_R", "hand_r"];
+
+// Which weapon model in `GameAssets::weapons` an item id is drawn with.
+const WEAPON_MODELS: &[(u32, &str)] = &[(100, "axe")];
+
+pub struct EquipmentPlugin;
+impl Plugin for EquipmentPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<EquipmentState>()
+            .add_systems(Update, track_equipment)
+            .add_systems(Update, (equip_input, update_weapon_attachments).run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+// Latest `Equipment` row per player, filled from table updates.
+#[derive(Resource, Default)]
+pub struct EquipmentState(pub HashMap<Identity, Equipment>);
+
+// The weapon currently attached to a player's hand.
+#[derive(Component)]
+pub struct EquippedWeapon {
+    pub item_id: Option<u32>,
+    pub attachment: Option<Entity>,
+}
+
+pub fn track_equipment(
+    mut equipment: ResMut<EquipmentState>,
+    mut event_reader: EventReader<UncbEvent>,
+) {
+    for event in event_reader.read() {
+        if let UncbMessage::EquipmentChanged { data } = &event.message {
+            equipment.0.insert(data.owner_id.clone(), data.clone());
+        }
+    }
+}
+
+// Toggles the main hand: puts the first weapon from the inventory in it, or puts it away.
+fn equip_input(
+    player_q: Query<&ActionState<GameActions>, With<PlayerMark>>,
+    equipment: Res<EquipmentState>,
+) {
+    let Ok(action_state) = player_q.get_single() else {
+        return;
+    };
+    if !action_state.just_pressed(&GameActions::Equip) {
+        return;
+    }
+    let Ok(owner_id) = identity() else {
+        return;
+    };
+
+    let main_hand = equipment.0.get(&owner_id).and_then(|e| e.main_hand);
+    if main_hand.is_some() {
+        unequip_item(EquipSlot::MainHand);
+        return;
+    }
+
+    let weapon_slot = InventorySlot::filter_by_owner_id(owner_id)
+        .filter(|stack| {
+            ItemDef::find_by_item_id(stack.item_id).is_some_and(|def| def.kind == ItemKind::Weapon)
+        })
+        .map(|stack| stack.slot)
+        .min();
+    match weapon_slot {
+        Some(slot) => equip_item(slot, EquipSlot::MainHand),
+        None => info!("No weapon to equip"),
+    }
+}
+
+fn find_bone(
+    root: Entity,
+    children_q: &Query<&Children>,
+    name_q: &Query<&Name>,
+) -> Option<Entity> {
+    HAND_BONES.iter().find_map(|bone| {
+        children_q
+            .iter_descendants(root)
+            .find(|entity| name_q.get(*entity).is_ok_and(|name| name.as_str() == *bone))
+    })
+}
+
+fn weapon_scene(
+    item_id: u32,
+    ga: &GameAssets,
+    gltf_assets: &Assets<Gltf>,
+) -> Option<Handle<Scene>> {
+    let (_, model) = WEAPON_MODELS.iter().find(|(id, _)| *id == item_id)?;
+    let (_, handle) = ga.weapons.iter().find(|(path, _)| path.contains(model))?;
+    gltf_assets.get(handle)?.scenes.first().cloned()
+}
+
+// Keeps the weapon in every player's hand in line with their `Equipment` row.
+// Scenes spawn asynchronously, so players whose skeleton isn't there yet are
+// retried on the next frame.
+pub fn update_weapon_attachments(
+    mut commands: Commands,
+    equipment: Res<EquipmentState>,
+    ga: Res<GameAssets>,
+    gltf_assets: Res<Assets<Gltf>>,
+    player_q: Query<(Entity, &Player, Option<&EquippedWeapon>)>,
+    children_q: Query<&Children>,
+    name_q: Query<&Name>,
+) {
+    for (entity, player, equipped) in player_q.iter() {
+        let wanted = equipment.0.get(&player.data.owner_id).and_then(|e| e.main_hand);
+        let current = equipped.and_then(|e| e.item_id);
+        if equipped.is_some() && wanted == current {
+            continue;
+        }
+
+        let attachment = match wanted {
+            Some(item_id) => {
+                let Some(bone) = find_bone(entity, &children_q, &name_q) else {
+                    continue;
+                };
+                let Some(scene) = weapon_scene(item_id, &ga, &gltf_assets) else {
+                    warn!("No weapon model for item {}", item_id);
+                    continue;
+                };
+                let weapon = commands.spawn(SceneBundle { scene, ..default() }).id();
+                commands.entity(bone).add_child(weapon);
+                Some(weapon)
+            }
+            None => None,
+        };
+
+        if let Some(old) = equipped.and_then(|e| e.attachment) {
+            commands.entity(old).despawn_recursive();
+        }
+        commands.entity(entity).insert(EquippedWeapon { item_id: wanted, attachment });
+    }
+}
@@ -12,8 +12,21 @@ pub enum GameActions {
     Jump,
     Crouch,
     CombatStance,
+    CycleStance,
     Equip,
     Attack,
+    Block,
+    Dodge,
+    Spell1,
+    Spell2,
+    Spell3,
+    Inventory,
+    Interact,
+    Hotbar1,
+    Hotbar2,
+    Hotbar3,
+    Hotbar4,
+    Hotbar5,
 }
 
 pub fn get_input_vector(
@@ -82,37 +95,48 @@ pub fn handle_stance_change(
             // Update player stance
                 entity.data.stance = match entity.data.stance {
                     PlayerStances::NonCombat => PlayerStances::Combat,
-                    PlayerStances::Combat => PlayerStances::NonCombat,
-                    _ => entity.data.stance.clone(),
+                    _ => PlayerStances::NonCombat,
                 };
                 println!("Player stance changed to: {:?}", entity.data.stance);
                 
                 return entity.data.stance.clone();
+        } else if action_state.just_pressed(&GameActions::CycleStance) {
+            // Cycle through the combat stances, drawing weapons first if needed.
+            entity.data.stance = match entity.data.stance {
+                PlayerStances::NonCombat => PlayerStances::Combat,
+                PlayerStances::Combat => PlayerStances::Precise,
+                PlayerStances::Precise => PlayerStances::Defensive,
+                PlayerStances::Defensive => PlayerStances::Combat,
+            };
+
+            return entity.data.stance.clone();
         } else {
             return entity.data.stance.clone();
         }
     
 }
 
-pub fn handle_attack(
-    //mut player_q: &mut Query<(&ActionState<GameActions>, &mut PlayerEntity)>,
+// Attack, block, dodge and spells are one-shot actions, they only last for the frame
+// they were pressed in. The server opens the matching window when it gets the call.
+pub fn handle_action(
     action_state: &ActionState<GameActions>,
     entity: &mut PlayerEntity
 ) -> PlayerAction {
-    //for (action_state, mut entity) in player_q.iter_mut() {
-        if action_state.just_pressed(&GameActions::Attack) {
-            // Update player stance
-               entity.data.action = match entity.data.action {
-                PlayerAction::None =>
-                PlayerAction::Attack,
-
-                _ => entity.data.action.clone()
-               };
+    entity.data.action = if action_state.just_pressed(&GameActions::Attack) {
+        PlayerAction::Attack
+    } else if action_state.just_pressed(&GameActions::Block) {
+        PlayerAction::Block
+    } else if action_state.just_pressed(&GameActions::Dodge) {
+        PlayerAction::Dodge
+    } else if action_state.just_pressed(&GameActions::Spell1) {
+        PlayerAction::CastSpell(1)
+    } else if action_state.just_pressed(&GameActions::Spell2) {
+        PlayerAction::CastSpell(2)
+    } else if action_state.just_pressed(&GameActions::Spell3) {
+        PlayerAction::CastSpell(3)
+    } else {
+        PlayerAction::None
+    };
 
-               println!("Player Attacked {:?}", entity.data.action);
-               return entity.data.action.clone();
-        } else {
-            return entity.data.action.clone();
-        }
-    
+    entity.data.action.clone()
 }
\ No newline at end of file
@@ -0,0 +1,349 @@
+use bevy::{prelude::*, utils::HashMap};
+use leafwing_input_manager::prelude::ActionState;
+use spacetimedb_sdk::identity::identity;
+
+use crate::{
+    input::GameActions,
+    module_bindings::{drop_item, move_item, pickup_item, use_item, InventorySlot, ItemDef, WorldItem},
+    network::uncb_receiver::{UncbEvent, UncbMessage},
+    player::{player_bundle::PlayerMark, HandleScenesState},
+};
+
+// Must match `INVENTORY_SIZE` in the server module.
+const INVENTORY_SIZE: u32 = 20;
+const HOTBAR_SIZE: u32 = 5;
+const PICKUP_RANGE: f32 = 1.0;
+
+const SLOT_COLOR: Color = Color::srgba(0.15, 0.15, 0.15, 0.9);
+const SELECTED_SLOT_COLOR: Color = Color::srgba(0.45, 0.35, 0.15, 0.9);
+
+pub struct InventoryPlugin;
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<InventoryUi>()
+            .init_resource::<WorldItemEntities>()
+            .add_systems(OnEnter(HandleScenesState::Done), setup_inventory_ui)
+            .add_systems(Update, sync_world_items)
+            .add_systems(Update, (
+                inventory_input,
+                slot_clicks,
+                inventory_buttons,
+                refresh_inventory,
+            ).chain().run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct InventoryUi {
+    pub open: bool,
+    pub selected: Option<u32>,
+    pub dirty: bool,
+}
+
+// Maps `WorldItem::world_item_id` to the Bevy entity showing it.
+#[derive(Resource, Default)]
+pub struct WorldItemEntities(pub HashMap<u64, Entity>);
+
+#[derive(Component)]
+struct InventoryPanel;
+
+#[derive(Component)]
+struct SlotButton(u32);
+
+#[derive(Component)]
+struct SlotText(u32);
+
+#[derive(Component)]
+enum InventoryButton {
+    Use,
+    Drop,
+}
+
+#[derive(Component)]
+pub struct WorldItemMarker(pub u64);
+
+fn spawn_slot(parent: &mut ChildBuilder, slot: u32) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(64.0),
+                    height: Val::Px(64.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: SLOT_COLOR.into(),
+                ..default()
+            },
+            SlotButton(slot),
+        ))
+        .with_children(|button| {
+            button.spawn((
+                TextBundle::from_section("", TextStyle { font_size: 12.0, ..default() }),
+                SlotText(slot),
+            ));
+        });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, kind: InventoryButton) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style { padding: UiRect::all(Val::Px(6.0)), ..default() },
+                background_color: SLOT_COLOR.into(),
+                ..default()
+            },
+            kind,
+        ))
+        .with_children(|button| {
+            button.spawn(TextBundle::from_section(label, TextStyle { font_size: 16.0, ..default() }));
+        });
+}
+
+pub fn setup_inventory_ui(mut commands: Commands, mut ui: ResMut<InventoryUi>) {
+    // Hotbar, always visible along the bottom of the screen.
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(12.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|hotbar| {
+            for slot in 0..HOTBAR_SIZE {
+                spawn_slot(hotbar, slot);
+            }
+        });
+
+    // The rest of the bag, toggled with GameActions::Inventory.
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(12.0),
+                    top: Val::Px(12.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            InventoryPanel,
+        ))
+        .with_children(|panel| {
+            panel
+                .spawn(NodeBundle {
+                    style: Style {
+                        display: Display::Grid,
+                        grid_template_columns: RepeatedGridTrack::px(5, 64.0),
+                        row_gap: Val::Px(4.0),
+                        column_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for slot in HOTBAR_SIZE..INVENTORY_SIZE {
+                        spawn_slot(grid, slot);
+                    }
+                });
+            panel
+                .spawn(NodeBundle {
+                    style: Style { column_gap: Val::Px(8.0), ..default() },
+                    ..default()
+                })
+                .with_children(|buttons| {
+                    spawn_button(buttons, "Use", InventoryButton::Use);
+                    spawn_button(buttons, "Drop", InventoryButton::Drop);
+                });
+        });
+
+    ui.dirty = true;
+}
+
+fn inventory_input(
+    mut ui: ResMut<InventoryUi>,
+    player_q: Query<(&ActionState<GameActions>, &Transform), With<PlayerMark>>,
+    mut panel_q: Query<&mut Visibility, With<InventoryPanel>>,
+) {
+    let Ok((action_state, transform)) = player_q.get_single() else {
+        return;
+    };
+
+    if action_state.just_pressed(&GameActions::Inventory) {
+        ui.open = !ui.open;
+        ui.selected = None;
+        ui.dirty = true;
+        for mut visibility in panel_q.iter_mut() {
+            *visibility = if ui.open { Visibility::Visible } else { Visibility::Hidden };
+        }
+    }
+
+    let hotbar = [
+        GameActions::Hotbar1,
+        GameActions::Hotbar2,
+        GameActions::Hotbar3,
+        GameActions::Hotbar4,
+        GameActions::Hotbar5,
+    ];
+    for (slot, action) in hotbar.iter().enumerate() {
+        if action_state.just_pressed(action) {
+            use_item(slot as u32);
+        }
+    }
+
+    if action_state.just_pressed(&GameActions::Interact) {
+        // Pick up the closest item in reach, the server checks the range again.
+        let position = transform.translation;
+        let closest = WorldItem::iter()
+            .map(|item| {
+                let distance = Vec2::new(item.position.x - position.x, item.position.z - position.z).length();
+                (item.world_item_id, distance)
+            })
+            .filter(|(_, distance)| *distance <= PICKUP_RANGE)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((world_item_id, _)) = closest {
+            pickup_item(world_item_id);
+        }
+    }
+}
+
+// Clicking a slot selects it, clicking a second slot moves the selected stack there.
+fn slot_clicks(
+    mut ui: ResMut<InventoryUi>,
+    slot_q: Query<(&Interaction, &SlotButton), Changed<Interaction>>,
+) {
+    for (interaction, slot_button) in slot_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match ui.selected {
+            Some(from) => {
+                move_item(from, slot_button.0);
+                ui.selected = None;
+            }
+            None => ui.selected = Some(slot_button.0),
+        }
+        ui.dirty = true;
+    }
+}
+
+fn inventory_buttons(
+    mut ui: ResMut<InventoryUi>,
+    button_q: Query<(&Interaction, &InventoryButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in button_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(slot) = ui.selected.take() else {
+            continue;
+        };
+        match button {
+            InventoryButton::Use => use_item(slot),
+            InventoryButton::Drop => drop_item(slot),
+        }
+        ui.dirty = true;
+    }
+}
+
+fn refresh_inventory(
+    mut ui: ResMut<InventoryUi>,
+    mut event_reader: EventReader<UncbEvent>,
+    mut text_q: Query<(&SlotText, &mut Text)>,
+    mut slot_q: Query<(&SlotButton, &mut BackgroundColor)>,
+) {
+    for event in event_reader.read() {
+        if let UncbMessage::InventoryChanged = event.message {
+            ui.dirty = true;
+        }
+    }
+    if !ui.dirty {
+        return;
+    }
+    ui.dirty = false;
+
+    let Ok(owner_id) = identity() else {
+        return;
+    };
+    let stacks: HashMap<u32, InventorySlot> = InventorySlot::filter_by_owner_id(owner_id)
+        .map(|stack| (stack.slot, stack))
+        .collect();
+
+    for (slot_text, mut text) in text_q.iter_mut() {
+        text.sections[0].value = match stacks.get(&slot_text.0) {
+            Some(stack) => {
+                let name = ItemDef::find_by_item_id(stack.item_id)
+                    .map(|def| def.name)
+                    .unwrap_or_else(|| format!("#{}", stack.item_id));
+                format!("{}\nx{}", name, stack.quantity)
+            }
+            None => String::new(),
+        };
+    }
+    for (slot_button, mut color) in slot_q.iter_mut() {
+        *color = if ui.selected == Some(slot_button.0) {
+            SELECTED_SLOT_COLOR.into()
+        } else {
+            SLOT_COLOR.into()
+        };
+    }
+}
+
+// Spawns a placeholder mesh for every item lying in the world and removes it again
+// once it is picked up. After a reconnect, items that went away in the meantime are dropped.
+pub fn sync_world_items(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    mut world_items: ResMut<WorldItemEntities>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::WorldItemInserted { data } => {
+                if world_items.0.contains_key(&data.world_item_id) {
+                    continue;
+                }
+                let entity = commands
+                    .spawn((
+                        PbrBundle {
+                            mesh: meshes.add(Cuboid::new(0.1, 0.1, 0.1)),
+                            material: materials.add(Color::srgb(0.8, 0.7, 0.2)),
+                            transform: Transform::from_xyz(data.position.x, data.position.y + 0.05, data.position.z),
+                            ..default()
+                        },
+                        WorldItemMarker(data.world_item_id),
+                    ))
+                    .id();
+                world_items.0.insert(data.world_item_id, entity);
+            }
+            UncbMessage::WorldItemRemoved { data } => {
+                if let Some(entity) = world_items.0.remove(&data.world_item_id) {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            UncbMessage::SubscriptionApplied => {
+                world_items.0.retain(|world_item_id, entity| {
+                    let exists = WorldItem::find_by_world_item_id(*world_item_id).is_some();
+                    if !exists {
+                        commands.entity(*entity).despawn_recursive();
+                    }
+                    exists
+                });
+            }
+            _ => {}
+        }
+    }
+}
@@ -5,12 +5,32 @@ pub mod input;
 mod module_bindings;
 mod asset_manager;
 mod camera;
+mod chat;
 mod animations;
+mod config;
+mod character_creation;
+mod combat;
+mod death;
+mod equipment;
+mod inventory;
+mod notifications;
+mod npc;
+mod spells;
 
 use animations::AnimPlugin;
 use asset_manager::LoadAssetPlugin;
 use bevy_rapier3d::{plugin::{NoUserData, RapierConfiguration, RapierPhysicsPlugin}, render::RapierDebugRenderPlugin};
 use camera::CameraPlugin;
+use chat::ChatPlugin;
+use config::ClientConfig;
+use character_creation::CharacterCreationPlugin;
+use combat::CombatPlugin;
+use death::DeathPlugin;
+use equipment::EquipmentPlugin;
+use inventory::InventoryPlugin;
+use notifications::NotificationsPlugin;
+use npc::NpcPlugin;
+use spells::SpellsPlugin;
 use bevy::prelude::*;
 use bevy_third_person_camera::*;
 use futures_channel::mpsc;
@@ -41,28 +61,44 @@ fn main() {
     // Initialize your logger
     env_logger::init();
 
+    let config = ClientConfig::load().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2)
+    });
+
     let (uncb_send, uncb_recv) = mpsc::unbounded();
 
-    register_callbacks(uncb_send.clone());
-    connect_to_db();
-    subscribe_to_tables();
+    register_callbacks(uncb_send.clone(), &config);
+    connect_to_db(&config);
+    // Centered on the origin until we know where our character is, see network/interest.rs.
+    subscribe_to_tables((0, 0));
 
     let mut app = App::new();
     app.insert_resource(UncbReceiver::new(uncb_recv))
+        .insert_resource(config)
         .add_event::<UncbEvent>()
         .add_plugins((
             NetworkPlugin,
             DefaultPlugins,
             CameraPlugin,
             LoadAssetPlugin,
+            CharacterCreationPlugin,
             HandleScenesPlugin,
             AnimPlugin,
+            CombatPlugin,
+            InventoryPlugin,
+            EquipmentPlugin,
+            SpellsPlugin,
             ThirdPersonCameraPlugin,
             TerrainPlugin,
             RapierPhysicsPlugin::<NoUserData>::default(),
             InputManagerPlugin::<GameActions>::default(),
             //RapierDebugRenderPlugin::default(),
         ))
+        .add_plugins(NotificationsPlugin)
+        .add_plugins(ChatPlugin)
+        .add_plugins(NpcPlugin)
+        .add_plugins(DeathPlugin)
         //.init_state::<GameState>()
         .add_systems(OnEnter(HandleScenesState::Spawned), setup)
         .run();
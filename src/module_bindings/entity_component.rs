@@ -23,6 +23,16 @@ pub struct EntityComponent {
     pub moving: bool,
     pub stance: PlayerStances,
     pub action: PlayerAction,
+    pub health: f32,
+    pub max_health: f32,
+    pub stamina: f32,
+    pub max_stamina: f32,
+    pub mana: f32,
+    pub max_mana: f32,
+    pub dead: bool,
+    pub input_sequence: u32,
+    pub cell_x: i32,
+    pub cell_z: i32,
 }
 
 impl TableType for EntityComponent {
@@ -54,4 +64,44 @@ impl EntityComponent {
     pub fn filter_by_moving(moving: bool) -> TableIter<Self> {
         Self::filter(|row| row.moving == moving)
     }
+    #[allow(unused)]
+    pub fn filter_by_health(health: f32) -> TableIter<Self> {
+        Self::filter(|row| row.health == health)
+    }
+    #[allow(unused)]
+    pub fn filter_by_max_health(max_health: f32) -> TableIter<Self> {
+        Self::filter(|row| row.max_health == max_health)
+    }
+    #[allow(unused)]
+    pub fn filter_by_stamina(stamina: f32) -> TableIter<Self> {
+        Self::filter(|row| row.stamina == stamina)
+    }
+    #[allow(unused)]
+    pub fn filter_by_max_stamina(max_stamina: f32) -> TableIter<Self> {
+        Self::filter(|row| row.max_stamina == max_stamina)
+    }
+    #[allow(unused)]
+    pub fn filter_by_mana(mana: f32) -> TableIter<Self> {
+        Self::filter(|row| row.mana == mana)
+    }
+    #[allow(unused)]
+    pub fn filter_by_max_mana(max_mana: f32) -> TableIter<Self> {
+        Self::filter(|row| row.max_mana == max_mana)
+    }
+    #[allow(unused)]
+    pub fn filter_by_dead(dead: bool) -> TableIter<Self> {
+        Self::filter(|row| row.dead == dead)
+    }
+    #[allow(unused)]
+    pub fn filter_by_input_sequence(input_sequence: u32) -> TableIter<Self> {
+        Self::filter(|row| row.input_sequence == input_sequence)
+    }
+    #[allow(unused)]
+    pub fn filter_by_cell_x(cell_x: i32) -> TableIter<Self> {
+        Self::filter(|row| row.cell_x == cell_x)
+    }
+    #[allow(unused)]
+    pub fn filter_by_cell_z(cell_z: i32) -> TableIter<Self> {
+        Self::filter(|row| row.cell_z == cell_z)
+    }
 }
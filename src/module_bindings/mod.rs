@@ -20,35 +20,208 @@ use spacetimedb_sdk::{
 };
 use std::sync::Arc;
 
+pub mod admin;
+pub mod attack_reducer;
+pub mod ban;
+pub mod ban_player_reducer;
+pub mod block_reducer;
+pub mod cast_spell_reducer;
+pub mod character;
+pub mod character_stats;
+pub mod chat_channel;
+pub mod chat_message;
+pub mod chat_rate_limit;
+pub mod chat_scope;
 pub mod client;
+pub mod combat_cooldown;
+pub mod combat_event;
+pub mod combat_event_kind;
+pub mod config;
+pub mod create_character_reducer;
 pub mod create_player_reducer;
+pub mod death;
+pub mod defense_window;
+pub mod dodge_reducer;
+pub mod drop_item_reducer;
 pub mod entity_component;
+pub mod equip_item_reducer;
+pub mod equip_slot;
+pub mod equipment;
+pub mod expire_spell_entity_reducer;
+pub mod grant_admin_reducer;
+pub mod inventory_slot;
+pub mod item_def;
+pub mod item_effect;
+pub mod item_kind;
+pub mod kick;
+pub mod kick_player_reducer;
+pub mod move_item_reducer;
+pub mod movement_config;
+pub mod movement_state;
+pub mod movement_violation;
+pub mod movement_violation_kind;
+pub mod mute;
+pub mod mute_player_reducer;
+pub mod npc;
+pub mod npc_def;
+pub mod npc_spawner;
+pub mod npc_state;
+pub mod pickup_item_reducer;
 pub mod player_action;
 pub mod player_component;
 pub mod player_stances;
+pub mod resolve_spell_reducer;
+pub mod respawn_reducer;
+pub mod revoke_admin_reducer;
+pub mod send_chat_reducer;
+pub mod set_max_players_reducer;
+pub mod set_max_speed_reducer;
+pub mod set_message_of_the_day_reducer;
+pub mod set_player_health_reducer;
+pub mod set_player_stance_reducer;
+pub mod set_tick_micros_reducer;
+pub mod spawn_point;
+pub mod spell_cast;
+pub mod spell_cooldown;
+pub mod spell_def;
+pub mod spell_effect;
+pub mod spell_entity;
+pub mod status_effect;
+pub mod status_effect_kind;
 pub mod stdb_vector_3;
-pub mod update_player_action_reducer;
+pub mod teleport_player_reducer;
+pub mod tick_projectile_reducer;
+pub mod unban_player_reducer;
+pub mod unequip_item_reducer;
+pub mod unmute_player_reducer;
 pub mod update_player_position_reducer;
 pub mod update_player_stance_reducer;
+pub mod update_player_state_reducer;
+pub mod use_item_reducer;
+pub mod world_clock;
+pub mod world_item;
+pub mod world_tick_reducer;
 
+pub use admin::*;
+pub use attack_reducer::*;
+pub use ban::*;
+pub use ban_player_reducer::*;
+pub use block_reducer::*;
+pub use cast_spell_reducer::*;
+pub use character::*;
+pub use character_stats::*;
+pub use chat_channel::*;
+pub use chat_message::*;
+pub use chat_rate_limit::*;
+pub use chat_scope::*;
 pub use client::*;
+pub use combat_cooldown::*;
+pub use combat_event::*;
+pub use combat_event_kind::*;
+pub use config::*;
+pub use create_character_reducer::*;
 pub use create_player_reducer::*;
+pub use death::*;
+pub use defense_window::*;
+pub use dodge_reducer::*;
+pub use drop_item_reducer::*;
 pub use entity_component::*;
+pub use equip_item_reducer::*;
+pub use equip_slot::*;
+pub use equipment::*;
+pub use expire_spell_entity_reducer::*;
+pub use grant_admin_reducer::*;
+pub use inventory_slot::*;
+pub use item_def::*;
+pub use item_effect::*;
+pub use item_kind::*;
+pub use kick::*;
+pub use kick_player_reducer::*;
+pub use move_item_reducer::*;
+pub use movement_config::*;
+pub use movement_state::*;
+pub use movement_violation::*;
+pub use movement_violation_kind::*;
+pub use mute::*;
+pub use mute_player_reducer::*;
+pub use npc::*;
+pub use npc_def::*;
+pub use npc_spawner::*;
+pub use npc_state::*;
+pub use pickup_item_reducer::*;
 pub use player_action::*;
 pub use player_component::*;
 pub use player_stances::*;
+pub use resolve_spell_reducer::*;
+pub use respawn_reducer::*;
+pub use revoke_admin_reducer::*;
+pub use send_chat_reducer::*;
+pub use set_max_players_reducer::*;
+pub use set_max_speed_reducer::*;
+pub use set_message_of_the_day_reducer::*;
+pub use set_player_health_reducer::*;
+pub use set_player_stance_reducer::*;
+pub use set_tick_micros_reducer::*;
+pub use spawn_point::*;
+pub use spell_cast::*;
+pub use spell_cooldown::*;
+pub use spell_def::*;
+pub use spell_effect::*;
+pub use spell_entity::*;
+pub use status_effect::*;
+pub use status_effect_kind::*;
 pub use stdb_vector_3::*;
-pub use update_player_action_reducer::*;
+pub use teleport_player_reducer::*;
+pub use tick_projectile_reducer::*;
+pub use unban_player_reducer::*;
+pub use unequip_item_reducer::*;
+pub use unmute_player_reducer::*;
 pub use update_player_position_reducer::*;
 pub use update_player_stance_reducer::*;
+pub use update_player_state_reducer::*;
+pub use use_item_reducer::*;
+pub use world_clock::*;
+pub use world_item::*;
+pub use world_tick_reducer::*;
 
 #[allow(unused)]
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub enum ReducerEvent {
+    Attack(attack_reducer::AttackArgs),
+    BanPlayer(ban_player_reducer::BanPlayerArgs),
+    Block(block_reducer::BlockArgs),
+    CastSpell(cast_spell_reducer::CastSpellArgs),
+    CreateCharacter(create_character_reducer::CreateCharacterArgs),
     CreatePlayer(create_player_reducer::CreatePlayerArgs),
-    UpdatePlayerAction(update_player_action_reducer::UpdatePlayerActionArgs),
+    Dodge(dodge_reducer::DodgeArgs),
+    DropItem(drop_item_reducer::DropItemArgs),
+    EquipItem(equip_item_reducer::EquipItemArgs),
+    ExpireSpellEntity(expire_spell_entity_reducer::ExpireSpellEntityArgs),
+    GrantAdmin(grant_admin_reducer::GrantAdminArgs),
+    KickPlayer(kick_player_reducer::KickPlayerArgs),
+    MoveItem(move_item_reducer::MoveItemArgs),
+    MutePlayer(mute_player_reducer::MutePlayerArgs),
+    PickupItem(pickup_item_reducer::PickupItemArgs),
+    ResolveSpell(resolve_spell_reducer::ResolveSpellArgs),
+    Respawn(respawn_reducer::RespawnArgs),
+    RevokeAdmin(revoke_admin_reducer::RevokeAdminArgs),
+    SendChat(send_chat_reducer::SendChatArgs),
+    SetMaxPlayers(set_max_players_reducer::SetMaxPlayersArgs),
+    SetMaxSpeed(set_max_speed_reducer::SetMaxSpeedArgs),
+    SetMessageOfTheDay(set_message_of_the_day_reducer::SetMessageOfTheDayArgs),
+    SetPlayerHealth(set_player_health_reducer::SetPlayerHealthArgs),
+    SetPlayerStance(set_player_stance_reducer::SetPlayerStanceArgs),
+    SetTickMicros(set_tick_micros_reducer::SetTickMicrosArgs),
+    TeleportPlayer(teleport_player_reducer::TeleportPlayerArgs),
+    TickProjectile(tick_projectile_reducer::TickProjectileArgs),
+    UnbanPlayer(unban_player_reducer::UnbanPlayerArgs),
+    UnequipItem(unequip_item_reducer::UnequipItemArgs),
+    UnmutePlayer(unmute_player_reducer::UnmutePlayerArgs),
     UpdatePlayerPosition(update_player_position_reducer::UpdatePlayerPositionArgs),
     UpdatePlayerStance(update_player_stance_reducer::UpdatePlayerStanceArgs),
+    UpdatePlayerState(update_player_state_reducer::UpdatePlayerStateArgs),
+    UseItem(use_item_reducer::UseItemArgs),
+    WorldTick(world_tick_reducer::WorldTickArgs),
 }
 
 #[allow(unused)]
@@ -62,18 +235,140 @@ impl SpacetimeModule for Module {
     ) {
         let table_name = &table_update.table_name[..];
         match table_name {
+            "Admin" => client_cache
+                .handle_table_update_with_primary_key::<admin::Admin>(callbacks, table_update),
+            "Ban" => client_cache
+                .handle_table_update_with_primary_key::<ban::Ban>(callbacks, table_update),
+            "Character" => client_cache
+                .handle_table_update_with_primary_key::<character::Character>(
+                    callbacks,
+                    table_update,
+                ),
+            "ChatChannel" => client_cache
+                .handle_table_update_with_primary_key::<chat_channel::ChatChannel>(
+                    callbacks,
+                    table_update,
+                ),
+            "ChatMessage" => client_cache
+                .handle_table_update_with_primary_key::<chat_message::ChatMessage>(
+                    callbacks,
+                    table_update,
+                ),
+            "ChatRateLimit" => client_cache
+                .handle_table_update_with_primary_key::<chat_rate_limit::ChatRateLimit>(
+                    callbacks,
+                    table_update,
+                ),
             "Client" => client_cache
                 .handle_table_update_with_primary_key::<client::Client>(callbacks, table_update),
+            "CombatCooldown" => client_cache
+                .handle_table_update_with_primary_key::<combat_cooldown::CombatCooldown>(
+                    callbacks,
+                    table_update,
+                ),
+            "CombatEvent" => client_cache
+                .handle_table_update_with_primary_key::<combat_event::CombatEvent>(
+                    callbacks,
+                    table_update,
+                ),
+            "Config" => client_cache
+                .handle_table_update_with_primary_key::<config::Config>(callbacks, table_update),
+            "Death" => client_cache
+                .handle_table_update_with_primary_key::<death::Death>(callbacks, table_update),
+            "DefenseWindow" => client_cache
+                .handle_table_update_with_primary_key::<defense_window::DefenseWindow>(
+                    callbacks,
+                    table_update,
+                ),
             "EntityComponent" => client_cache
                 .handle_table_update_with_primary_key::<entity_component::EntityComponent>(
                     callbacks,
                     table_update,
                 ),
+            "Equipment" => client_cache
+                .handle_table_update_with_primary_key::<equipment::Equipment>(
+                    callbacks,
+                    table_update,
+                ),
+            "InventorySlot" => client_cache
+                .handle_table_update_with_primary_key::<inventory_slot::InventorySlot>(
+                    callbacks,
+                    table_update,
+                ),
+            "ItemDef" => client_cache
+                .handle_table_update_with_primary_key::<item_def::ItemDef>(callbacks, table_update),
+            "Kick" => client_cache
+                .handle_table_update_with_primary_key::<kick::Kick>(callbacks, table_update),
+            "MovementConfig" => client_cache
+                .handle_table_update_with_primary_key::<movement_config::MovementConfig>(
+                    callbacks,
+                    table_update,
+                ),
+            "MovementState" => client_cache
+                .handle_table_update_with_primary_key::<movement_state::MovementState>(
+                    callbacks,
+                    table_update,
+                ),
+            "MovementViolation" => client_cache
+                .handle_table_update_with_primary_key::<movement_violation::MovementViolation>(
+                    callbacks,
+                    table_update,
+                ),
+            "Mute" => client_cache
+                .handle_table_update_with_primary_key::<mute::Mute>(callbacks, table_update),
+            "Npc" => client_cache
+                .handle_table_update_with_primary_key::<npc::Npc>(callbacks, table_update),
+            "NpcDef" => client_cache
+                .handle_table_update_with_primary_key::<npc_def::NpcDef>(callbacks, table_update),
+            "NpcSpawner" => client_cache
+                .handle_table_update_with_primary_key::<npc_spawner::NpcSpawner>(
+                    callbacks,
+                    table_update,
+                ),
             "PlayerComponent" => client_cache
                 .handle_table_update_with_primary_key::<player_component::PlayerComponent>(
                     callbacks,
                     table_update,
                 ),
+            "SpawnPoint" => client_cache
+                .handle_table_update_with_primary_key::<spawn_point::SpawnPoint>(
+                    callbacks,
+                    table_update,
+                ),
+            "SpellCast" => client_cache
+                .handle_table_update_with_primary_key::<spell_cast::SpellCast>(
+                    callbacks,
+                    table_update,
+                ),
+            "SpellCooldown" => client_cache
+                .handle_table_update_with_primary_key::<spell_cooldown::SpellCooldown>(
+                    callbacks,
+                    table_update,
+                ),
+            "SpellDef" => client_cache.handle_table_update_with_primary_key::<spell_def::SpellDef>(
+                callbacks,
+                table_update,
+            ),
+            "SpellEntity" => client_cache
+                .handle_table_update_with_primary_key::<spell_entity::SpellEntity>(
+                    callbacks,
+                    table_update,
+                ),
+            "StatusEffect" => client_cache
+                .handle_table_update_with_primary_key::<status_effect::StatusEffect>(
+                    callbacks,
+                    table_update,
+                ),
+            "WorldClock" => client_cache
+                .handle_table_update_with_primary_key::<world_clock::WorldClock>(
+                    callbacks,
+                    table_update,
+                ),
+            "WorldItem" => client_cache
+                .handle_table_update_with_primary_key::<world_item::WorldItem>(
+                    callbacks,
+                    table_update,
+                ),
             _ => {
                 spacetimedb_sdk::log::error!("TableRowOperation on unknown table {:?}", table_name)
             }
@@ -86,17 +381,59 @@ impl SpacetimeModule for Module {
         reducer_event: Option<Arc<AnyReducerEvent>>,
         state: &Arc<ClientCache>,
     ) {
+        reminders.invoke_callbacks::<admin::Admin>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<ban::Ban>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<character::Character>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<chat_channel::ChatChannel>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<chat_message::ChatMessage>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<chat_rate_limit::ChatRateLimit>(worker, &reducer_event, state);
         reminders.invoke_callbacks::<client::Client>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<combat_cooldown::CombatCooldown>(
+            worker,
+            &reducer_event,
+            state,
+        );
+        reminders.invoke_callbacks::<combat_event::CombatEvent>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<config::Config>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<death::Death>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<defense_window::DefenseWindow>(worker, &reducer_event, state);
         reminders.invoke_callbacks::<entity_component::EntityComponent>(
             worker,
             &reducer_event,
             state,
         );
+        reminders.invoke_callbacks::<equipment::Equipment>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<inventory_slot::InventorySlot>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<item_def::ItemDef>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<kick::Kick>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<movement_config::MovementConfig>(
+            worker,
+            &reducer_event,
+            state,
+        );
+        reminders.invoke_callbacks::<movement_state::MovementState>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<movement_violation::MovementViolation>(
+            worker,
+            &reducer_event,
+            state,
+        );
+        reminders.invoke_callbacks::<mute::Mute>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<npc::Npc>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<npc_def::NpcDef>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<npc_spawner::NpcSpawner>(worker, &reducer_event, state);
         reminders.invoke_callbacks::<player_component::PlayerComponent>(
             worker,
             &reducer_event,
             state,
         );
+        reminders.invoke_callbacks::<spawn_point::SpawnPoint>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<spell_cast::SpellCast>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<spell_cooldown::SpellCooldown>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<spell_def::SpellDef>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<spell_entity::SpellEntity>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<status_effect::StatusEffect>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<world_clock::WorldClock>(worker, &reducer_event, state);
+        reminders.invoke_callbacks::<world_item::WorldItem>(worker, &reducer_event, state);
     }
     fn handle_event(
         &self,
@@ -110,10 +447,41 @@ impl SpacetimeModule for Module {
         };
         #[allow(clippy::match_single_binding)]
 match &function_call.reducer[..] {
-						"create_player" => _reducer_callbacks.handle_event_of_type::<create_player_reducer::CreatePlayerArgs, ReducerEvent>(event, _state, ReducerEvent::CreatePlayer),
-			"update_player_action" => _reducer_callbacks.handle_event_of_type::<update_player_action_reducer::UpdatePlayerActionArgs, ReducerEvent>(event, _state, ReducerEvent::UpdatePlayerAction),
+						"attack" => _reducer_callbacks.handle_event_of_type::<attack_reducer::AttackArgs, ReducerEvent>(event, _state, ReducerEvent::Attack),
+			"ban_player" => _reducer_callbacks.handle_event_of_type::<ban_player_reducer::BanPlayerArgs, ReducerEvent>(event, _state, ReducerEvent::BanPlayer),
+			"block" => _reducer_callbacks.handle_event_of_type::<block_reducer::BlockArgs, ReducerEvent>(event, _state, ReducerEvent::Block),
+			"cast_spell" => _reducer_callbacks.handle_event_of_type::<cast_spell_reducer::CastSpellArgs, ReducerEvent>(event, _state, ReducerEvent::CastSpell),
+			"create_character" => _reducer_callbacks.handle_event_of_type::<create_character_reducer::CreateCharacterArgs, ReducerEvent>(event, _state, ReducerEvent::CreateCharacter),
+			"create_player" => _reducer_callbacks.handle_event_of_type::<create_player_reducer::CreatePlayerArgs, ReducerEvent>(event, _state, ReducerEvent::CreatePlayer),
+			"dodge" => _reducer_callbacks.handle_event_of_type::<dodge_reducer::DodgeArgs, ReducerEvent>(event, _state, ReducerEvent::Dodge),
+			"drop_item" => _reducer_callbacks.handle_event_of_type::<drop_item_reducer::DropItemArgs, ReducerEvent>(event, _state, ReducerEvent::DropItem),
+			"equip_item" => _reducer_callbacks.handle_event_of_type::<equip_item_reducer::EquipItemArgs, ReducerEvent>(event, _state, ReducerEvent::EquipItem),
+			"expire_spell_entity" => _reducer_callbacks.handle_event_of_type::<expire_spell_entity_reducer::ExpireSpellEntityArgs, ReducerEvent>(event, _state, ReducerEvent::ExpireSpellEntity),
+			"grant_admin" => _reducer_callbacks.handle_event_of_type::<grant_admin_reducer::GrantAdminArgs, ReducerEvent>(event, _state, ReducerEvent::GrantAdmin),
+			"kick_player" => _reducer_callbacks.handle_event_of_type::<kick_player_reducer::KickPlayerArgs, ReducerEvent>(event, _state, ReducerEvent::KickPlayer),
+			"move_item" => _reducer_callbacks.handle_event_of_type::<move_item_reducer::MoveItemArgs, ReducerEvent>(event, _state, ReducerEvent::MoveItem),
+			"mute_player" => _reducer_callbacks.handle_event_of_type::<mute_player_reducer::MutePlayerArgs, ReducerEvent>(event, _state, ReducerEvent::MutePlayer),
+			"pickup_item" => _reducer_callbacks.handle_event_of_type::<pickup_item_reducer::PickupItemArgs, ReducerEvent>(event, _state, ReducerEvent::PickupItem),
+			"resolve_spell" => _reducer_callbacks.handle_event_of_type::<resolve_spell_reducer::ResolveSpellArgs, ReducerEvent>(event, _state, ReducerEvent::ResolveSpell),
+			"respawn" => _reducer_callbacks.handle_event_of_type::<respawn_reducer::RespawnArgs, ReducerEvent>(event, _state, ReducerEvent::Respawn),
+			"revoke_admin" => _reducer_callbacks.handle_event_of_type::<revoke_admin_reducer::RevokeAdminArgs, ReducerEvent>(event, _state, ReducerEvent::RevokeAdmin),
+			"send_chat" => _reducer_callbacks.handle_event_of_type::<send_chat_reducer::SendChatArgs, ReducerEvent>(event, _state, ReducerEvent::SendChat),
+			"set_max_players" => _reducer_callbacks.handle_event_of_type::<set_max_players_reducer::SetMaxPlayersArgs, ReducerEvent>(event, _state, ReducerEvent::SetMaxPlayers),
+			"set_max_speed" => _reducer_callbacks.handle_event_of_type::<set_max_speed_reducer::SetMaxSpeedArgs, ReducerEvent>(event, _state, ReducerEvent::SetMaxSpeed),
+			"set_message_of_the_day" => _reducer_callbacks.handle_event_of_type::<set_message_of_the_day_reducer::SetMessageOfTheDayArgs, ReducerEvent>(event, _state, ReducerEvent::SetMessageOfTheDay),
+			"set_player_health" => _reducer_callbacks.handle_event_of_type::<set_player_health_reducer::SetPlayerHealthArgs, ReducerEvent>(event, _state, ReducerEvent::SetPlayerHealth),
+			"set_player_stance" => _reducer_callbacks.handle_event_of_type::<set_player_stance_reducer::SetPlayerStanceArgs, ReducerEvent>(event, _state, ReducerEvent::SetPlayerStance),
+			"set_tick_micros" => _reducer_callbacks.handle_event_of_type::<set_tick_micros_reducer::SetTickMicrosArgs, ReducerEvent>(event, _state, ReducerEvent::SetTickMicros),
+			"teleport_player" => _reducer_callbacks.handle_event_of_type::<teleport_player_reducer::TeleportPlayerArgs, ReducerEvent>(event, _state, ReducerEvent::TeleportPlayer),
+			"tick_projectile" => _reducer_callbacks.handle_event_of_type::<tick_projectile_reducer::TickProjectileArgs, ReducerEvent>(event, _state, ReducerEvent::TickProjectile),
+			"unban_player" => _reducer_callbacks.handle_event_of_type::<unban_player_reducer::UnbanPlayerArgs, ReducerEvent>(event, _state, ReducerEvent::UnbanPlayer),
+			"unequip_item" => _reducer_callbacks.handle_event_of_type::<unequip_item_reducer::UnequipItemArgs, ReducerEvent>(event, _state, ReducerEvent::UnequipItem),
+			"unmute_player" => _reducer_callbacks.handle_event_of_type::<unmute_player_reducer::UnmutePlayerArgs, ReducerEvent>(event, _state, ReducerEvent::UnmutePlayer),
 			"update_player_position" => _reducer_callbacks.handle_event_of_type::<update_player_position_reducer::UpdatePlayerPositionArgs, ReducerEvent>(event, _state, ReducerEvent::UpdatePlayerPosition),
 			"update_player_stance" => _reducer_callbacks.handle_event_of_type::<update_player_stance_reducer::UpdatePlayerStanceArgs, ReducerEvent>(event, _state, ReducerEvent::UpdatePlayerStance),
+			"update_player_state" => _reducer_callbacks.handle_event_of_type::<update_player_state_reducer::UpdatePlayerStateArgs, ReducerEvent>(event, _state, ReducerEvent::UpdatePlayerState),
+			"use_item" => _reducer_callbacks.handle_event_of_type::<use_item_reducer::UseItemArgs, ReducerEvent>(event, _state, ReducerEvent::UseItem),
+			"world_tick" => _reducer_callbacks.handle_event_of_type::<world_tick_reducer::WorldTickArgs, ReducerEvent>(event, _state, ReducerEvent::WorldTick),
 			unknown => { spacetimedb_sdk::log::error!("Event on an unknown reducer: {:?}", unknown); None }
 }
     }
@@ -125,17 +493,85 @@ match &function_call.reducer[..] {
     ) {
         let table_name = &new_subs.table_name[..];
         match table_name {
+            "Admin" => {
+                client_cache.handle_resubscribe_for_type::<admin::Admin>(callbacks, new_subs)
+            }
+            "Ban" => client_cache.handle_resubscribe_for_type::<ban::Ban>(callbacks, new_subs),
+            "Character" => client_cache
+                .handle_resubscribe_for_type::<character::Character>(callbacks, new_subs),
+            "ChatChannel" => client_cache
+                .handle_resubscribe_for_type::<chat_channel::ChatChannel>(callbacks, new_subs),
+            "ChatMessage" => client_cache
+                .handle_resubscribe_for_type::<chat_message::ChatMessage>(callbacks, new_subs),
+            "ChatRateLimit" => client_cache
+                .handle_resubscribe_for_type::<chat_rate_limit::ChatRateLimit>(callbacks, new_subs),
             "Client" => {
                 client_cache.handle_resubscribe_for_type::<client::Client>(callbacks, new_subs)
             }
+            "CombatCooldown" => client_cache
+                .handle_resubscribe_for_type::<combat_cooldown::CombatCooldown>(
+                    callbacks, new_subs,
+                ),
+            "CombatEvent" => client_cache
+                .handle_resubscribe_for_type::<combat_event::CombatEvent>(callbacks, new_subs),
+            "Config" => {
+                client_cache.handle_resubscribe_for_type::<config::Config>(callbacks, new_subs)
+            }
+            "Death" => {
+                client_cache.handle_resubscribe_for_type::<death::Death>(callbacks, new_subs)
+            }
+            "DefenseWindow" => client_cache
+                .handle_resubscribe_for_type::<defense_window::DefenseWindow>(callbacks, new_subs),
             "EntityComponent" => client_cache
                 .handle_resubscribe_for_type::<entity_component::EntityComponent>(
                     callbacks, new_subs,
                 ),
+            "Equipment" => client_cache
+                .handle_resubscribe_for_type::<equipment::Equipment>(callbacks, new_subs),
+            "InventorySlot" => client_cache
+                .handle_resubscribe_for_type::<inventory_slot::InventorySlot>(callbacks, new_subs),
+            "ItemDef" => {
+                client_cache.handle_resubscribe_for_type::<item_def::ItemDef>(callbacks, new_subs)
+            }
+            "Kick" => client_cache.handle_resubscribe_for_type::<kick::Kick>(callbacks, new_subs),
+            "MovementConfig" => client_cache
+                .handle_resubscribe_for_type::<movement_config::MovementConfig>(
+                    callbacks, new_subs,
+                ),
+            "MovementState" => client_cache
+                .handle_resubscribe_for_type::<movement_state::MovementState>(callbacks, new_subs),
+            "MovementViolation" => client_cache
+                .handle_resubscribe_for_type::<movement_violation::MovementViolation>(
+                    callbacks, new_subs,
+                ),
+            "Mute" => client_cache.handle_resubscribe_for_type::<mute::Mute>(callbacks, new_subs),
+            "Npc" => client_cache.handle_resubscribe_for_type::<npc::Npc>(callbacks, new_subs),
+            "NpcDef" => {
+                client_cache.handle_resubscribe_for_type::<npc_def::NpcDef>(callbacks, new_subs)
+            }
+            "NpcSpawner" => client_cache
+                .handle_resubscribe_for_type::<npc_spawner::NpcSpawner>(callbacks, new_subs),
             "PlayerComponent" => client_cache
                 .handle_resubscribe_for_type::<player_component::PlayerComponent>(
                     callbacks, new_subs,
                 ),
+            "SpawnPoint" => client_cache
+                .handle_resubscribe_for_type::<spawn_point::SpawnPoint>(callbacks, new_subs),
+            "SpellCast" => client_cache
+                .handle_resubscribe_for_type::<spell_cast::SpellCast>(callbacks, new_subs),
+            "SpellCooldown" => client_cache
+                .handle_resubscribe_for_type::<spell_cooldown::SpellCooldown>(callbacks, new_subs),
+            "SpellDef" => {
+                client_cache.handle_resubscribe_for_type::<spell_def::SpellDef>(callbacks, new_subs)
+            }
+            "SpellEntity" => client_cache
+                .handle_resubscribe_for_type::<spell_entity::SpellEntity>(callbacks, new_subs),
+            "StatusEffect" => client_cache
+                .handle_resubscribe_for_type::<status_effect::StatusEffect>(callbacks, new_subs),
+            "WorldClock" => client_cache
+                .handle_resubscribe_for_type::<world_clock::WorldClock>(callbacks, new_subs),
+            "WorldItem" => client_cache
+                .handle_resubscribe_for_type::<world_item::WorldItem>(callbacks, new_subs),
             _ => {
                 spacetimedb_sdk::log::error!("TableRowOperation on unknown table {:?}", table_name)
             }
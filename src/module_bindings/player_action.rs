@@ -23,5 +23,5 @@ pub enum PlayerAction {
     Dodge,
 
     UseItem(u32),
-    CastSpell(String),
+    CastSpell(u32),
 }
@@ -16,6 +16,7 @@ use spacetimedb_sdk::{
 pub struct PlayerComponent {
     pub entity_id: u64,
     pub owner_id: Identity,
+    pub username: String,
 }
 
 impl TableType for PlayerComponent {
@@ -47,4 +48,8 @@ impl PlayerComponent {
     pub fn find_by_owner_id(owner_id: Identity) -> Option<Self> {
         Self::find(|row| row.owner_id == owner_id)
     }
+    #[allow(unused)]
+    pub fn filter_by_username(username: String) -> TableIter<Self> {
+        Self::filter(|row| row.username == username)
+    }
 }
@@ -18,6 +18,7 @@ pub struct UpdatePlayerPositionArgs {
     pub position: StdbVector3,
     pub direction: f32,
     pub moving: bool,
+    pub sequence: u32,
 }
 
 impl Reducer for UpdatePlayerPositionArgs {
@@ -25,18 +26,19 @@ impl Reducer for UpdatePlayerPositionArgs {
 }
 
 #[allow(unused)]
-pub fn update_player_position(position: StdbVector3, direction: f32, moving: bool) {
+pub fn update_player_position(position: StdbVector3, direction: f32, moving: bool, sequence: u32) {
     UpdatePlayerPositionArgs {
         position,
         direction,
         moving,
+        sequence,
     }
     .invoke();
 }
 
 #[allow(unused)]
 pub fn on_update_player_position(
-    mut __callback: impl FnMut(&Identity, Option<Address>, &Status, &StdbVector3, &f32, &bool)
+    mut __callback: impl FnMut(&Identity, Option<Address>, &Status, &StdbVector3, &f32, &bool, &u32)
         + Send
         + 'static,
 ) -> ReducerCallbackId<UpdatePlayerPositionArgs> {
@@ -45,14 +47,17 @@ pub fn on_update_player_position(
             position,
             direction,
             moving,
+            sequence,
         } = __args;
-        __callback(__identity, __addr, __status, position, direction, moving);
+        __callback(
+            __identity, __addr, __status, position, direction, moving, sequence,
+        );
     })
 }
 
 #[allow(unused)]
 pub fn once_on_update_player_position(
-    __callback: impl FnOnce(&Identity, Option<Address>, &Status, &StdbVector3, &f32, &bool)
+    __callback: impl FnOnce(&Identity, Option<Address>, &Status, &StdbVector3, &f32, &bool, &u32)
         + Send
         + 'static,
 ) -> ReducerCallbackId<UpdatePlayerPositionArgs> {
@@ -61,8 +66,11 @@ pub fn once_on_update_player_position(
             position,
             direction,
             moving,
+            sequence,
         } = __args;
-        __callback(__identity, __addr, __status, position, direction, moving);
+        __callback(
+            __identity, __addr, __status, position, direction, moving, sequence,
+        );
     })
 }
 
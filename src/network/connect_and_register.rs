@@ -1,55 +1,128 @@
 //use bevy::prelude::*;
 use log::{error, info};
-use super::uncb_receiver;
+use super::{interest::INTEREST_RADIUS, uncb_receiver};
 use uncb_receiver::{UncbMessage, UncbSend};
 
 use spacetimedb_sdk::{
     Address,
     //disconnect,
-    identity::{load_credentials, once_on_connect, save_credentials, Credentials, Identity},
-    on_disconnect, //on_subscription_applied,
+    anyhow::Result,
+    identity::{credentials, load_credentials, on_connect, save_credentials, Credentials, Identity},
+    on_disconnect, on_subscription_applied,
+    reducer::Status,
     subscribe,
     table::{TableType, TableWithPrimaryKey},
 };
 
-use crate::{module_bindings::{client, connect, Client, EntityComponent, PlayerComponent, ReducerEvent}, 
+use crate::{config::ClientConfig, module_bindings::{client, connect, on_ban_player, on_create_character, on_create_player, on_grant_admin, on_kick_player, on_mute_player, on_respawn, on_revoke_admin, on_send_chat, on_set_max_players, on_set_max_speed, on_set_message_of_the_day, on_set_player_health, on_set_player_stance, on_set_tick_micros, on_teleport_player, on_unban_player, on_unmute_player, ChatMessage, Client, CombatEvent, EntityComponent, Equipment, InventorySlot, PlayerComponent, ReducerEvent, SpellCast, SpellEntity, WorldItem}, 
 //GameState
 };
 
 
 
-const SPACETIMEDB_URI: &str = "http://127.0.0.1:3000";
-const DB_NAME: &str = "dagalok";
-const CREDS_DIR: &str = ".dagalok";
-const DEBUG_MODE: bool = true;
-
-/// Register all the callbacks our app will use to respond to database events.
-pub fn connect_to_db() {
+/// Connect to the database named in the config, see config.rs.
+pub fn connect_to_db(config: &ClientConfig) {
+    info!("Connecting to {} at {}", config.db_name, config.uri);
     connect(
-        SPACETIMEDB_URI,
-        DB_NAME,
-        if DEBUG_MODE {
+        config.uri.as_str(),
+        &config.db_name,
+        if config.debug_mode {
             None
         } else {
-            load_credentials(CREDS_DIR).expect("Error reading stored credentials")
+            load_credentials(&config.credentials_dir()).expect("Error reading stored credentials")
         },
     )
     .expect("Failed to connect");
 }
 
+/// Connects again after the connection dropped, keeping the identity we had so
+/// the server gives us back our character.
+pub fn reconnect_to_db(config: &ClientConfig) -> Result<()> {
+    connect(config.uri.as_str(), &config.db_name, credentials().ok())
+}
+
+
+// Public tables small enough to receive in full. Each `subscribe` call replaces
+// the previous set, so these are sent again whenever the entity query changes.
+const GLOBAL_TABLES: &[&str] = &[
+    "Config",
+    "PlayerComponent",
+    "CombatEvent",
+    "ItemDef",
+    "WorldItem",
+    "Equipment",
+    "SpellDef",
+    "SpellCast",
+    "SpellEntity",
+    "NpcDef",
+    "Npc",
+    "SpawnPoint",
+    "Death",
+    "ChatChannel",
+];
+
+// Tables where we only need our own rows, with the column that holds the owner.
+const OWNED_TABLES: &[(&str, &str)] = &[
+    ("Character", "owner_id"),
+    ("InventorySlot", "owner_id"),
+    ("ChatMessage", "recipient_id"),
+];
 
-/// Register subscriptions for all rows of tables.
-pub fn subscribe_to_tables() {
-    if let Err(e) = subscribe(&["SELECT * FROM *"]) {
+/// Register subscriptions for the global tables, our own rows once we have an
+/// identity, and the `EntityComponent` rows in the cells around `center`, see
+/// network/interest.rs.
+pub fn subscribe_to_tables(center: (i32, i32)) {
+    let (cell_x, cell_z) = center;
+    let mut queries: Vec<String> = GLOBAL_TABLES
+        .iter()
+        .map(|table| format!("SELECT * FROM {}", table))
+        .collect();
+    if let Ok(owner_id) = spacetimedb_sdk::identity::identity() {
+        let owner_hex = hex::encode(owner_id.bytes());
+        queries.extend(OWNED_TABLES.iter().map(|(table, column)| {
+            format!("SELECT * FROM {} WHERE {} = 0x{}", table, column, owner_hex)
+        }));
+    }
+    queries.push(format!(
+        "SELECT * FROM EntityComponent WHERE cell_x >= {} AND cell_x <= {} AND cell_z >= {} AND cell_z <= {}",
+        cell_x - INTEREST_RADIUS,
+        cell_x + INTEREST_RADIUS,
+        cell_z - INTEREST_RADIUS,
+        cell_z + INTEREST_RADIUS,
+    ));
+
+    let queries: Vec<&str> = queries.iter().map(String::as_str).collect();
+    if let Err(e) = subscribe(&queries) {
         error!("Failed to subscribe to tables: {:?}", e);
     }
 }
 
 
 //#region callbacks
-pub fn register_callbacks(uncb_send: UncbSend) {
-    once_on_connect(on_connected(uncb_send.clone()));
+// The admin reducers behind the chat commands, see chat/mod.rs. They only differ
+// in their arguments, which are ignored, so one closure per reducer is generated.
+macro_rules! report_command_failures {
+    ($uncb_send:ident, $($on_reducer:ident($($arg:tt),*)),* $(,)?) => {
+        $({
+            let uncb_send = $uncb_send.clone();
+            $on_reducer(move |caller, _address, status, $($arg),*| {
+                if !is_local_identity(caller) {
+                    return;
+                }
+                if let Status::Failed(reason) = status {
+                    uncb_send
+                        .unbounded_send(UncbMessage::CommandRejected { reason: reason.clone() })
+                        .unwrap();
+                }
+            });
+        })*
+    };
+}
+
+pub fn register_callbacks(uncb_send: UncbSend, config: &ClientConfig) {
+    on_connect(on_connected(uncb_send.clone(), config.credentials_dir()));
     on_disconnect(on_disconnected(uncb_send.clone()));
+    on_subscription_applied(on_subscribed(uncb_send.clone()));
 
     EntityComponent::on_insert(on_entity_inserted(uncb_send.clone()));
     EntityComponent::on_update(on_entity_updated(uncb_send.clone()));
@@ -61,11 +134,54 @@ pub fn register_callbacks(uncb_send: UncbSend) {
     PlayerComponent::on_insert(on_player_inserted(uncb_send.clone()));
     PlayerComponent::on_update(on_player_updated(uncb_send.clone()));
     PlayerComponent::on_delete(on_player_deleted(uncb_send.clone()));
+
+    CombatEvent::on_insert(on_combat_event_inserted(uncb_send.clone()));
+
+    InventorySlot::on_insert(on_inventory_slot_inserted(uncb_send.clone()));
+    InventorySlot::on_update(on_inventory_slot_updated(uncb_send.clone()));
+    InventorySlot::on_delete(on_inventory_slot_deleted(uncb_send.clone()));
+
+    WorldItem::on_insert(on_world_item_inserted(uncb_send.clone()));
+    WorldItem::on_delete(on_world_item_deleted(uncb_send.clone()));
+
+    Equipment::on_insert(on_equipment_inserted(uncb_send.clone()));
+    Equipment::on_update(on_equipment_updated(uncb_send.clone()));
+
+    SpellCast::on_insert(on_spell_cast_inserted(uncb_send.clone()));
+    SpellCast::on_delete(on_spell_cast_deleted(uncb_send.clone()));
+
+    SpellEntity::on_insert(on_spell_entity_inserted(uncb_send.clone()));
+    SpellEntity::on_delete(on_spell_entity_deleted(uncb_send.clone()));
+
+    ChatMessage::on_insert(on_chat_message_inserted(uncb_send.clone()));
+
+    on_create_character(on_character_created(uncb_send.clone()));
+    on_create_player(on_player_joined(uncb_send.clone()));
+    on_send_chat(on_chat_sent(uncb_send.clone()));
+    on_respawn(on_respawned(uncb_send.clone()));
+
+    report_command_failures!(uncb_send,
+        on_grant_admin(_),
+        on_revoke_admin(_),
+        on_kick_player(_),
+        on_ban_player(_, _),
+        on_unban_player(_),
+        on_mute_player(_, _),
+        on_unmute_player(_),
+        on_teleport_player(_, _),
+        on_set_player_stance(_, _),
+        on_set_player_health(_, _),
+        on_set_message_of_the_day(_),
+        on_set_max_players(_),
+        on_set_max_speed(_),
+        on_set_tick_micros(_),
+    );
 }
 
-fn on_connected(uncb_send: UncbSend) -> impl FnMut(&Credentials, Address) + Send + 'static {
+
+fn on_connected(uncb_send: UncbSend, creds_dir: String) -> impl FnMut(&Credentials, Address) + Send + 'static {
     move |creds, address| {
-        if let Err(e) = save_credentials(CREDS_DIR, creds) {
+        if let Err(e) = save_credentials(&creds_dir, creds) {
             eprintln!("Failed to save credentials: {:?}", e);
         }
         uncb_send
@@ -92,26 +208,113 @@ fn on_connected(uncb_send: UncbSend) -> impl FnMut(&Credentials, Address) + Send
 fn on_disconnected(uncb_send: UncbSend) -> impl FnMut() + Send + 'static {
     move || {
         eprintln!("Disconnected!");
+        // See network/reconnect.rs.
         uncb_send.unbounded_send(UncbMessage::Disconnected).unwrap();
-        std::process::exit(0)
     }
 }
 
-fn on_entity_inserted(
+fn on_subscribed(uncb_send: UncbSend) -> impl FnMut() + Send + 'static {
+    move || {
+        uncb_send.unbounded_send(UncbMessage::SubscriptionApplied).unwrap();
+    }
+}
+
+fn on_character_created(
     uncb_send: UncbSend,
-) -> impl FnMut(&EntityComponent, Option<&ReducerEvent>) + Send + 'static {
-    move |entity, event| {
-        if let Some(event) = event {
+) -> impl FnMut(&Identity, Option<Address>, &Status, &String) + Send + 'static {
+    move |caller, _address, status, _username| {
+        if !is_local_identity(caller) {
+            return;
+        }
+        let message = match status {
+            Status::Committed => UncbMessage::CharacterCreated,
+            Status::Failed(reason) => UncbMessage::CharacterRejected { reason: reason.clone() },
+            Status::OutOfEnergy => UncbMessage::CharacterRejected { reason: "Server is out of energy".to_string() },
+        };
+        uncb_send.unbounded_send(message).unwrap();
+    }
+}
+
+// Success shows up as our own `PlayerComponent`, failures on the character
+// screen, see character_creation/mod.rs.
+fn on_player_joined(
+    uncb_send: UncbSend,
+) -> impl FnMut(&Identity, Option<Address>, &Status) + Send + 'static {
+    move |caller, _address, status| {
+        if !is_local_identity(caller) {
+            return;
+        }
+        let reason = match status {
+            Status::Committed => return,
+            Status::Failed(reason) => reason.clone(),
+            Status::OutOfEnergy => "Server is out of energy".to_string(),
+        };
+        uncb_send.unbounded_send(UncbMessage::JoinRejected { reason }).unwrap();
+    }
+}
+
+// The subscription only has rows addressed to us, but a stale one may still be
+// around right after our identity changes.
+fn on_chat_message_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&ChatMessage, Option<&ReducerEvent>) + Send + 'static {
+    move |message, _event| {
+        if is_local_identity(&message.recipient_id) {
             uncb_send
-                .unbounded_send(UncbMessage::EntityInserted {
-                    data: entity.clone(),
-                    event: Some(event.clone()),
+                .unbounded_send(UncbMessage::ChatMessageInserted {
+                    data: message.clone(),
                 })
                 .unwrap();
         }
     }
 }
 
+fn on_chat_sent(
+    uncb_send: UncbSend,
+) -> impl FnMut(&Identity, Option<Address>, &Status, &u32, &String, &Option<String>) + Send + 'static {
+    move |caller, _address, status, _channel_id, _text, _whisper_to| {
+        if !is_local_identity(caller) {
+            return;
+        }
+        if let Status::Failed(reason) = status {
+            uncb_send
+                .unbounded_send(UncbMessage::ChatRejected { reason: reason.clone() })
+                .unwrap();
+        }
+    }
+}
+
+// Failures show up on the death screen, see death/mod.rs.
+fn on_respawned(
+    uncb_send: UncbSend,
+) -> impl FnMut(&Identity, Option<Address>, &Status) + Send + 'static {
+    move |caller, _address, status| {
+        if !is_local_identity(caller) {
+            return;
+        }
+        if let Status::Failed(reason) = status {
+            uncb_send
+                .unbounded_send(UncbMessage::RespawnRejected { reason: reason.clone() })
+                .unwrap();
+        }
+    }
+}
+
+fn on_entity_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&EntityComponent, Option<&ReducerEvent>) + Send + 'static {
+    move |entity, event| {
+        // Without an event the row came in through the subscription, e.g. a
+        // player walking into one of our cells.
+        uncb_send
+            .unbounded_send(UncbMessage::EntityInserted {
+                data: entity.clone(),
+                event: event.cloned(),
+            })
+            .unwrap();
+    }
+}
+
 fn on_entity_updated(
     uncb_send: UncbSend,
 ) -> impl FnMut(&EntityComponent, &EntityComponent, Option<&ReducerEvent>) + Send + 'static {
@@ -132,14 +335,13 @@ fn on_entity_deleted(
     uncb_send: UncbSend,
 ) -> impl FnMut(&EntityComponent, Option<&ReducerEvent>) + Send + 'static {
     move |entity, event| {
-        if let Some(event) = event {
-            uncb_send
-                .unbounded_send(UncbMessage::EntityRemoved {
-                    data: entity.clone(),
-                    event: event.clone(),
-                })
-                .unwrap();
-        }
+        // Without an event the row left our subscription rather than the database.
+        uncb_send
+            .unbounded_send(UncbMessage::EntityRemoved {
+                data: entity.clone(),
+                event: event.cloned(),
+            })
+            .unwrap();
     }
 }
 
@@ -206,16 +408,146 @@ fn on_player_updated(
 fn on_player_deleted(
     uncb_send: UncbSend,
 ) -> impl FnMut(&PlayerComponent, Option<&ReducerEvent>) + Send + 'static {
-    move |player, _event| {
+    move |player, event| {
         info!("UncbMessage::PlayerRemoved called");
         uncb_send
             .unbounded_send(UncbMessage::PlayerRemoved {
                 data: player.clone(),
+                event: event.cloned(),
             })
             .unwrap();
     }
 }
 
+fn on_combat_event_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&CombatEvent, Option<&ReducerEvent>) + Send + 'static {
+    move |combat_event, event| {
+        // Only live hits, not the backlog delivered with the initial subscription.
+        if event.is_some() {
+            uncb_send
+                .unbounded_send(UncbMessage::CombatEventInserted {
+                    data: combat_event.clone(),
+                })
+                .unwrap();
+        }
+    }
+}
+
+fn is_local_identity(id: &Identity) -> bool {
+    spacetimedb_sdk::identity::identity().ok().as_ref() == Some(id)
+}
+
+fn on_inventory_slot_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&InventorySlot, Option<&ReducerEvent>) + Send + 'static {
+    move |slot, _event| {
+        if is_local_identity(&slot.owner_id) {
+            uncb_send.unbounded_send(UncbMessage::InventoryChanged).unwrap();
+        }
+    }
+}
+
+fn on_inventory_slot_updated(
+    uncb_send: UncbSend,
+) -> impl FnMut(&InventorySlot, &InventorySlot, Option<&ReducerEvent>) + Send + 'static {
+    move |_old, new, _event| {
+        if is_local_identity(&new.owner_id) {
+            uncb_send.unbounded_send(UncbMessage::InventoryChanged).unwrap();
+        }
+    }
+}
+
+fn on_inventory_slot_deleted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&InventorySlot, Option<&ReducerEvent>) + Send + 'static {
+    move |slot, _event| {
+        if is_local_identity(&slot.owner_id) {
+            uncb_send.unbounded_send(UncbMessage::InventoryChanged).unwrap();
+        }
+    }
+}
+
+fn on_world_item_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&WorldItem, Option<&ReducerEvent>) + Send + 'static {
+    move |item, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::WorldItemInserted { data: item.clone() })
+            .unwrap();
+    }
+}
+
+fn on_world_item_deleted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&WorldItem, Option<&ReducerEvent>) + Send + 'static {
+    move |item, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::WorldItemRemoved { data: item.clone() })
+            .unwrap();
+    }
+}
+
+fn on_equipment_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&Equipment, Option<&ReducerEvent>) + Send + 'static {
+    move |equipment, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::EquipmentChanged { data: equipment.clone() })
+            .unwrap();
+    }
+}
+
+fn on_equipment_updated(
+    uncb_send: UncbSend,
+) -> impl FnMut(&Equipment, &Equipment, Option<&ReducerEvent>) + Send + 'static {
+    move |_old, new, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::EquipmentChanged { data: new.clone() })
+            .unwrap();
+    }
+}
+
+fn on_spell_cast_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&SpellCast, Option<&ReducerEvent>) + Send + 'static {
+    move |cast, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::SpellCastInserted { data: cast.clone() })
+            .unwrap();
+    }
+}
+
+fn on_spell_cast_deleted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&SpellCast, Option<&ReducerEvent>) + Send + 'static {
+    move |cast, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::SpellCastRemoved { data: cast.clone() })
+            .unwrap();
+    }
+}
+
+fn on_spell_entity_inserted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&SpellEntity, Option<&ReducerEvent>) + Send + 'static {
+    move |spell, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::SpellEntityInserted { data: spell.clone() })
+            .unwrap();
+    }
+}
+
+fn on_spell_entity_deleted(
+    uncb_send: UncbSend,
+) -> impl FnMut(&SpellEntity, Option<&ReducerEvent>) + Send + 'static {
+    move |spell, _event| {
+        uncb_send
+            .unbounded_send(UncbMessage::SpellEntityRemoved { data: spell.clone() })
+            .unwrap();
+    }
+}
+
 fn identity_leading_hex(id: &Identity) -> String {
     hex::encode(&id.bytes()[0..8])
 }
@@ -0,0 +1,61 @@
+use bevy::{prelude::*, utils::HashMap};
+use spacetimedb_sdk::identity::Identity;
+
+use crate::{npc::NpcMark, player::player_bundle::Player};
+
+// The Bevy entity of every spawned player and NPC by `EntityComponent::entity_id`,
+// and of players by owner. Synced with the `Player` and `NpcMark` components in
+// `PreUpdate`, so systems in `Update` can go from a table row to its entity
+// without walking all of them.
+#[derive(Resource, Default)]
+pub struct NetworkEntityMap {
+    by_entity_id: HashMap<u64, Entity>,
+    by_owner: HashMap<Identity, Entity>,
+}
+
+impl NetworkEntityMap {
+    pub fn get(&self, entity_id: u64) -> Option<Entity> {
+        self.by_entity_id.get(&entity_id).copied()
+    }
+
+    pub fn get_by_owner(&self, owner_id: &Identity) -> Option<Entity> {
+        self.by_owner.get(owner_id).copied()
+    }
+
+    pub fn contains(&self, entity_id: u64) -> bool {
+        self.by_entity_id.contains_key(&entity_id)
+    }
+
+    fn insert(&mut self, entity: Entity, player: &Player) {
+        self.by_entity_id.insert(player.data.entity_id, entity);
+        self.by_owner.insert(player.data.owner_id.clone(), entity);
+    }
+
+    fn insert_npc(&mut self, entity: Entity, npc: &NpcMark) {
+        self.by_entity_id.insert(npc.data.entity_id, entity);
+    }
+
+    // By Bevy entity, a player that respawned under the same ids keeps its new entry.
+    fn remove(&mut self, entity: Entity) {
+        self.by_entity_id.retain(|_, mapped| *mapped != entity);
+        self.by_owner.retain(|_, mapped| *mapped != entity);
+    }
+}
+
+pub fn sync_network_entity_map(
+    mut entity_map: ResMut<NetworkEntityMap>,
+    added_q: Query<(Entity, &Player), Added<Player>>,
+    added_npc_q: Query<(Entity, &NpcMark), Added<NpcMark>>,
+    mut removed: RemovedComponents<Player>,
+    mut removed_npcs: RemovedComponents<NpcMark>,
+) {
+    for entity in removed.read().chain(removed_npcs.read()) {
+        entity_map.remove(entity);
+    }
+    for (entity, player) in added_q.iter() {
+        entity_map.insert(entity, player);
+    }
+    for (entity, npc) in added_npc_q.iter() {
+        entity_map.insert_npc(entity, npc);
+    }
+}
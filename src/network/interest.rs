@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::identity::{identity, Identity};
+
+use crate::{
+    module_bindings::{Character, PlayerComponent},
+    player::{
+        departure::Departing,
+        player_bundle::{PlayerEntity, PlayerMark},
+    },
+};
+
+use super::{
+    connect_and_register::subscribe_to_tables,
+    entity_map::NetworkEntityMap,
+    uncb_receiver::{UncbEvent, UncbMessage},
+    SubscriptionApplied,
+};
+
+// Must match `CELL_SIZE` in the server module.
+pub const CELL_SIZE: f32 = 16.0;
+// How many cells around the local player's cell we receive entities from.
+pub const INTEREST_RADIUS: i32 = 1;
+
+// How far past the edge of the center cell the local player has to go before the
+// subscription is moved. Walking back and forth over a boundary re-sends everything
+// otherwise, see `subscribe_to_tables`.
+pub const INTEREST_MARGIN: f32 = 4.0;
+
+// What the current subscription was made for.
+#[derive(Resource, Default)]
+pub struct InterestCell {
+    // The cell the `EntityComponent` query is centered on.
+    pub center: Option<(i32, i32)>,
+    // Whose own rows are included. Unknown until the first connect hands us an identity.
+    pub owner: Option<Identity>,
+}
+
+pub fn cell_of(x: f32, z: f32) -> (i32, i32) {
+    ((x / CELL_SIZE).floor() as i32, (z / CELL_SIZE).floor() as i32)
+}
+
+// Whether (x, z) is within `INTEREST_MARGIN` of `cell`.
+fn near_cell(cell: (i32, i32), x: f32, z: f32) -> bool {
+    let (min_x, min_z) = (cell.0 as f32 * CELL_SIZE, cell.1 as f32 * CELL_SIZE);
+    x >= min_x - INTEREST_MARGIN
+        && x <= min_x + CELL_SIZE + INTEREST_MARGIN
+        && z >= min_z - INTEREST_MARGIN
+        && z <= min_z + CELL_SIZE + INTEREST_MARGIN
+}
+
+// Re-subscribes when the local player moves well into another cell, or once our
+// identity is known. Before our player is spawned, the character's saved position
+// is used instead so our own entity is part of the first subscription that matters.
+pub fn update_interest(
+    mut interest: ResMut<InterestCell>,
+    subscribed: Res<SubscriptionApplied>,
+    player_q: Query<&Transform, With<PlayerMark>>,
+) {
+    if !subscribed.0 {
+        return;
+    }
+
+    let owner = identity().ok();
+    let position = match player_q.get_single() {
+        Ok(transform) => Some((transform.translation.x, transform.translation.z)),
+        Err(_) => owner
+            .clone()
+            .and_then(Character::find_by_owner_id)
+            .map(|character| (character.position.x, character.position.z)),
+    };
+    let center = match (position, interest.center) {
+        (Some((x, z)), Some(current)) if near_cell(current, x, z) => current,
+        (Some((x, z)), _) => cell_of(x, z),
+        // Our character isn't known until its row comes in with our identity.
+        (None, current) => current.unwrap_or_default(),
+    };
+
+    if interest.center != Some(center) || interest.owner != owner {
+        info!("Entering cell {:?}, updating subscriptions", center);
+        subscribe_to_tables(center);
+        interest.center = Some(center);
+        interest.owner = owner;
+    }
+}
+
+// Remote players whose entity dropped out of our subscription are despawned. They
+// stay in `PlayerDataResource` and are spawned again once their entity comes back.
+// Players that left the world altogether are handled by `player_removed_data`.
+pub fn despawn_out_of_interest(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    entity_map: Res<NetworkEntityMap>,
+    player_q: Query<(), (With<PlayerEntity>, Without<PlayerMark>, Without<Departing>)>,
+) {
+    for event in event_reader.read() {
+        let UncbMessage::EntityRemoved { data, .. } = &event.message else {
+            continue;
+        };
+        if PlayerComponent::find_by_entity_id(data.entity_id).is_none() {
+            continue;
+        }
+        if let Some(entity) = entity_map.get(data.entity_id).filter(|e| player_q.contains(*e)) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
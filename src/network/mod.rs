@@ -1,15 +1,26 @@
 pub(crate) mod uncb_receiver;
 pub(crate) mod player_connected;
 pub(crate) mod connect_and_register;
+pub(crate) mod entity_map;
+pub(crate) mod interest;
 pub(crate) mod player_updates;
+pub(crate) mod reconnect;
+pub(crate) mod send_scheduler;
 
 use bevy::prelude::*;
 
+use crate::{module_bindings::Config, notifications::Notify};
 
-use player_connected::{player_connected_data, NewPlayer, PlayerDataResource};
-use uncb_receiver::process_messages;
 
-use crate::module_bindings::create_player;
+use interest::{despawn_out_of_interest, update_interest, InterestCell};
+use send_scheduler::{SendMetrics, SendScheduler};
+use entity_map::{sync_network_entity_map, NetworkEntityMap};
+use player_connected::{player_connected_data, player_removed_data, NewPlayer, PlayerDataResource};
+use reconnect::{
+    despawn_reconnect_overlay, handle_disconnect, resync_after_reconnect, spawn_reconnect_overlay,
+    try_reconnect, update_reconnect_overlay, ConnectionState, Reconnect,
+};
+use uncb_receiver::{process_messages, UncbEvent, UncbMessage};
 
 pub struct NetworkPlugin;
 
@@ -18,8 +29,45 @@ impl Plugin for NetworkPlugin {
         app
             .init_resource::<PlayerDataResource>()
             .init_resource::<NewPlayer>()
-            .add_systems(Startup, create_player)
-            .add_systems(Update, (process_messages, player_connected_data));
+            .init_resource::<SubscriptionApplied>()
+            .init_resource::<Reconnect>()
+            .init_resource::<InterestCell>()
+            .init_resource::<NetworkEntityMap>()
+            .init_resource::<SendScheduler>()
+            .init_resource::<SendMetrics>()
+            .init_state::<ConnectionState>()
+            .add_systems(PreUpdate, sync_network_entity_map)
+            .add_systems(Update, (process_messages, player_connected_data, player_removed_data, track_subscription, handle_disconnect))
+            .add_systems(Update, (update_interest, despawn_out_of_interest).run_if(in_state(ConnectionState::Connected)))
+            .add_systems(Update, try_reconnect.run_if(in_state(ConnectionState::Reconnecting)))
+            .add_systems(Update, resync_after_reconnect.run_if(in_state(ConnectionState::Resyncing)))
+            .add_systems(Update, update_reconnect_overlay.run_if(not(in_state(ConnectionState::Connected))))
+            .add_systems(OnEnter(ConnectionState::Reconnecting), spawn_reconnect_overlay)
+            .add_systems(OnEnter(ConnectionState::Connected), despawn_reconnect_overlay);
+    }
+}
+
+// Set once the initial subscription has been applied and the client cache is
+// safe to read, e.g. to check whether the local player already has a character.
+#[derive(Resource, Default)]
+pub struct SubscriptionApplied(pub bool);
+
+// Also greets the player with the message of the day, once per session since
+// every interest change applies a new subscription.
+fn track_subscription(
+    mut subscribed: ResMut<SubscriptionApplied>,
+    mut event_reader: EventReader<UncbEvent>,
+    mut notify: EventWriter<Notify>,
+) {
+    for event in event_reader.read() {
+        if let UncbMessage::SubscriptionApplied = event.message {
+            if !subscribed.0 {
+                if let Some(config) = Config::find_by_version(0).filter(|c| !c.message_of_the_day.is_empty()) {
+                    notify.send(Notify(config.message_of_the_day));
+                }
+            }
+            subscribed.0 = true;
+        }
     }
 }
 
@@ -1,7 +1,18 @@
 use bevy::prelude::*;
-use crate::player::player_bundle::Player;
+use spacetimedb_sdk::identity::identity;
 
-use super::uncb_receiver::{UncbEvent, UncbMessage};
+use crate::{
+    config::ClientConfig,
+    notifications::Notify,
+    player::{
+        departure::Departing,
+        interpolation::SnapshotBuffer,
+        player_bundle::{Player, PlayerMark},
+        spawn_player::SceneEntitys,
+    },
+};
+
+use super::{entity_map::NetworkEntityMap, reconnect::ConnectionState, uncb_receiver::{UncbEvent, UncbMessage}};
 
 
 #[derive(Resource, Default)]
@@ -17,21 +28,26 @@ pub fn player_connected_data(
     mut event_reader: EventReader<UncbEvent>,
 
 ) {
-    let mut new_non_main_player_inserted = false;
-    commands.insert_resource(NewPlayer(new_non_main_player_inserted));
+    let mut new_player_inserted = false;
+    commands.insert_resource(NewPlayer(new_player_inserted));
     //let mut player_map = Vec::new();
 
     for event in event_reader.read() {
+        // A known player's entity came into one of our cells, see network/interest.rs.
+        if let UncbMessage::EntityInserted { data, event: _ } = &event.message {
+            if player_data.player_vec.iter().any(|p| p.data.entity_id == data.entity_id) {
+                new_player_inserted = true;
+            }
+        }
+
         if let UncbMessage::PlayerInserted { data, event: _ } = &event.message {
-            println!("{:?}", data);
             info!("PlayerInserted event received for player: {:?}", data.owner_id);
 
             if !player_data.player_vec.iter().any(|p| p.data.entity_id == data.entity_id) {
                 
-                if data.owner_id != spacetimedb_sdk::identity::identity().unwrap() {
-                    new_non_main_player_inserted = true;
-                    
-                } 
+                // Our own player only comes through here when rejoining after a
+                // reconnect, the first spawn happens in `spawn_players`.
+                new_player_inserted = true;
 
                 player_data.player_vec.push(Player{data: data.clone()});
                 
@@ -44,14 +60,71 @@ pub fn player_connected_data(
         }
     }
 
-    if new_non_main_player_inserted {
-        println!("NEW NON-MAIN PLAYER ENTERS THE FREY");
+    if new_player_inserted {
+        info!("A player entered our cells, spawning it");
 
-        let np = NewPlayer(new_non_main_player_inserted);
+        let np = NewPlayer(new_player_inserted);
         commands.insert_resource(np);
 
     }
 }
 
+// Players that left the world are forgotten and their scene faded out, or
+// despawned right away when `ClientConfig::departure_fade` is 0. Our own player
+// is left alone after a reconnect, `resync_after_reconnect` deals with it.
+pub fn player_removed_data(
+    mut commands: Commands,
+    mut player_data: ResMut<PlayerDataResource>,
+    mut event_reader: EventReader<UncbEvent>,
+    mut notify: EventWriter<Notify>,
+    mut scene_entities: Option<ResMut<SceneEntitys>>,
+    config: Res<ClientConfig>,
+    entity_map: Res<NetworkEntityMap>,
+    player_q: Query<(), (With<Player>, Without<PlayerMark>)>,
+    connection_state: Res<State<ConnectionState>>,
+    own_q: Query<Entity, With<PlayerMark>>,
+) {
+    for event in event_reader.read() {
+        let UncbMessage::PlayerRemoved { data, event: _ } = &event.message else {
+            continue;
+        };
+        if identity().is_ok_and(|own| own == data.owner_id) {
+            // While connected only a kick or ban takes us out, see `kick_player`
+            // in the server module. The character screen says which and lets us
+            // try to rejoin, see character_creation/mod.rs.
+            if *connection_state.get() == ConnectionState::Connected {
+                warn!("Removed from the world by the server");
+                player_data.player_vec.retain(|p| p.data.entity_id != data.entity_id);
+                for entity in own_q.iter() {
+                    if let Some(scene_entities) = scene_entities.as_mut() {
+                        scene_entities.0.retain(|_, scene_entity| *scene_entity != entity);
+                    }
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            continue;
+        }
+
+        info!("Player {} left the world", data.username);
+        notify.send(Notify(format!("{} left the area", data.username)));
+        player_data.player_vec.retain(|p| p.data.entity_id != data.entity_id);
+
+        if let Some(entity) = entity_map.get(data.entity_id).filter(|e| player_q.contains(*e)) {
+            if let Some(scene_entities) = scene_entities.as_mut() {
+                scene_entities.0.retain(|_, scene_entity| *scene_entity != entity);
+            }
+            if config.departure_fade > 0.0 {
+                // Without `Player` it drops out of movement, animation and combat queries.
+                commands
+                    .entity(entity)
+                    .remove::<(Player, SnapshotBuffer)>()
+                    .insert(Departing::new(config.departure_fade));
+            } else {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct NewPlayer(pub bool);
\ No newline at end of file
@@ -1,22 +1,37 @@
 use bevy::prelude::*;
 
-use crate::{animations::setup_animations::Animations, player::player_bundle::PlayerEntity};
+use crate::player::{
+    interpolation::{Snapshot, SnapshotBuffer},
+    player_bundle::{PlayerEntity, PlayerMark},
+};
 
-use super::{player_connected::PlayerDataResource, uncb_receiver::{UncbEvent, UncbMessage}};
+use super::{entity_map::NetworkEntityMap, uncb_receiver::{UncbEvent, UncbMessage}};
 
+// Applies `EntityUpdated` rows to remote players and NPCs. Their transform follows
+// the snapshots through `interpolate_positions`, the local player predicts its own.
 pub fn handle_player_updates(
+    time: Res<Time>,
+    entity_map: Res<NetworkEntityMap>,
     mut event_reader: EventReader<UncbEvent>,
-    mut player_data: ResMut<PlayerDataResource>,
-    query: Query<&PlayerEntity>,
-    animations: Res<Animations>,
+    mut player_q: Query<(&mut PlayerEntity, &mut SnapshotBuffer), Without<PlayerMark>>,
 ) {
-
     for event in event_reader.read() {
-        if let UncbMessage::EntityUpdated { old, new, event: _, } = &event.message {
-            for players in player_data.player_vec.iter() {
-                
-                
-            }
-        }
+        let UncbMessage::EntityUpdated { new, .. } = &event.message else {
+            continue;
+        };
+        let Some(entity) = entity_map.get(new.entity_id) else {
+            continue;
+        };
+        let Ok((mut player_entity, mut snapshots)) = player_q.get_mut(entity) else {
+            continue;
+        };
+
+        snapshots.push(Snapshot {
+            time: time.elapsed_seconds_f64(),
+            position: Vec3::new(new.position.x, new.position.y, new.position.z),
+            direction: new.direction,
+            moving: new.moving,
+        });
+        player_entity.data = new.clone();
     }
-}
\ No newline at end of file
+}
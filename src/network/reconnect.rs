@@ -0,0 +1,216 @@
+use bevy::{
+    prelude::*,
+    tasks::{block_on, futures_lite::future, IoTaskPool, Task},
+};
+use spacetimedb_sdk::{anyhow::Result, identity::identity};
+
+use crate::{
+    config::ClientConfig,
+    module_bindings::{create_player, Character, PlayerComponent},
+    player::{player_bundle::Player, HandleScenesState},
+};
+
+use super::{
+    connect_and_register::{reconnect_to_db, subscribe_to_tables},
+    interest::InterestCell,
+    player_connected::PlayerDataResource,
+    uncb_receiver::{UncbEvent, UncbMessage},
+};
+
+const INITIAL_BACKOFF_SECS: f32 = 1.0;
+const MAX_BACKOFF_SECS: f32 = 30.0;
+
+#[derive(States, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub enum ConnectionState {
+    #[default]
+    Connected,
+    // Waiting for the backoff timer or the `connect` attempt in flight.
+    Reconnecting,
+    // Connected again, waiting for the subscription to be re-applied.
+    Resyncing,
+}
+
+#[derive(Resource)]
+pub struct Reconnect {
+    pub attempt: u32,
+    pub timer: Timer,
+    // `connect` blocks until the handshake is done or times out, so it runs on
+    // the IO pool and is polled from `try_reconnect`.
+    task: Option<Task<Result<()>>>,
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Self {
+            attempt: 0,
+            timer: Timer::from_seconds(INITIAL_BACKOFF_SECS, TimerMode::Once),
+            task: None,
+        }
+    }
+}
+
+impl Reconnect {
+    // Doubles the wait after every failed attempt, up to `MAX_BACKOFF_SECS`.
+    fn backoff(attempt: u32) -> f32 {
+        (INITIAL_BACKOFF_SECS * 2f32.powi(attempt.min(16) as i32)).min(MAX_BACKOFF_SECS)
+    }
+}
+
+#[derive(Component)]
+struct ReconnectOverlay;
+
+#[derive(Component)]
+struct ReconnectText;
+
+pub fn handle_disconnect(
+    mut event_reader: EventReader<UncbEvent>,
+    state: Res<State<ConnectionState>>,
+    mut next_state: ResMut<NextState<ConnectionState>>,
+    mut reconnect: ResMut<Reconnect>,
+) {
+    for event in event_reader.read() {
+        if let UncbMessage::Disconnected = event.message {
+            if *state.get() != ConnectionState::Reconnecting {
+                warn!("Lost connection to the server, reconnecting");
+                *reconnect = Reconnect::default();
+                next_state.set(ConnectionState::Reconnecting);
+            }
+        }
+    }
+}
+
+pub fn try_reconnect(
+    config: Res<ClientConfig>,
+    interest: Res<InterestCell>,
+    time: Res<Time>,
+    mut reconnect: ResMut<Reconnect>,
+    mut next_state: ResMut<NextState<ConnectionState>>,
+) {
+    let Some(task) = reconnect.task.as_mut() else {
+        reconnect.timer.tick(time.delta());
+        if reconnect.timer.finished() {
+            let config = config.clone();
+            reconnect.task = Some(IoTaskPool::get().spawn(async move { reconnect_to_db(&config) }));
+        }
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    reconnect.task = None;
+
+    match result {
+        Ok(()) => {
+            info!("Reconnected after {} attempt(s)", reconnect.attempt + 1);
+            subscribe_to_tables(interest.center.unwrap_or_default());
+            next_state.set(ConnectionState::Resyncing);
+        }
+        Err(e) => {
+            reconnect.attempt += 1;
+            let wait = Reconnect::backoff(reconnect.attempt);
+            warn!("Reconnect attempt {} failed: {:?}, retrying in {}s", reconnect.attempt, e, wait);
+            reconnect.timer = Timer::from_seconds(wait, TimerMode::Once);
+        }
+    }
+}
+
+// Once the fresh subscription is in, drops players that left while we were gone,
+// announces the ones that joined, and puts our own character back into the world.
+pub fn resync_after_reconnect(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    mut event_writer: EventWriter<UncbEvent>,
+    mut player_data: ResMut<PlayerDataResource>,
+    player_q: Query<(Entity, &Player)>,
+    scenes_state: Res<State<HandleScenesState>>,
+    mut next_state: ResMut<NextState<ConnectionState>>,
+) {
+    if !event_reader.read().any(|event| matches!(event.message, UncbMessage::SubscriptionApplied)) {
+        return;
+    }
+
+    for (entity, player) in player_q.iter() {
+        if PlayerComponent::find_by_entity_id(player.data.entity_id).is_none() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+    player_data
+        .player_vec
+        .retain(|player| PlayerComponent::find_by_entity_id(player.data.entity_id).is_some());
+
+    for player in PlayerComponent::iter() {
+        if !player_data.player_vec.iter().any(|p| p.data.entity_id == player.entity_id) {
+            event_writer.send(UncbEvent {
+                message: UncbMessage::PlayerInserted { data: player, event: None },
+            });
+        }
+    }
+
+    // The server removes our entity when we drop, rejoin if we were in the game.
+    if *scenes_state.get() == HandleScenesState::Done {
+        if let Ok(owner_id) = identity() {
+            let joined = PlayerComponent::find_by_owner_id(owner_id.clone()).is_some();
+            if !joined && Character::find_by_owner_id(owner_id).is_some() {
+                create_player();
+            }
+        }
+    }
+
+    info!("Resynced with the server");
+    next_state.set(ConnectionState::Connected);
+}
+
+pub fn spawn_reconnect_overlay(mut commands: Commands, overlay_q: Query<(), With<ReconnectOverlay>>) {
+    // Still up if the connection dropped again while resyncing.
+    if !overlay_q.is_empty() {
+        return;
+    }
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+                z_index: ZIndex::Global(100),
+                ..default()
+            },
+            ReconnectOverlay,
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                TextBundle::from_section("Reconnecting…", TextStyle { font_size: 32.0, ..default() }),
+                ReconnectText,
+            ));
+        });
+}
+
+pub fn update_reconnect_overlay(
+    reconnect: Res<Reconnect>,
+    state: Res<State<ConnectionState>>,
+    mut text_q: Query<&mut Text, With<ReconnectText>>,
+) {
+    for mut text in text_q.iter_mut() {
+        text.sections[0].value = match state.get() {
+            ConnectionState::Resyncing => "Reconnecting… restoring world".to_string(),
+            _ if reconnect.task.is_some() => format!("Reconnecting… attempt {}", reconnect.attempt + 1),
+            _ if reconnect.attempt == 0 => "Reconnecting…".to_string(),
+            _ => format!(
+                "Reconnecting… attempt {} failed, retrying in {:.0}s",
+                reconnect.attempt,
+                reconnect.timer.remaining_secs().ceil()
+            ),
+        };
+    }
+}
+
+pub fn despawn_reconnect_overlay(mut commands: Commands, overlay_q: Query<Entity, With<ReconnectOverlay>>) {
+    for entity in overlay_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
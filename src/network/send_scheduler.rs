@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use spacetimedb_sdk::{reducer::Reducer, sats::bsatn};
+
+use crate::{
+    config::ClientConfig,
+    module_bindings::{PlayerAction, PlayerStances, StdbVector3, UpdatePlayerStateArgs},
+    player::{
+        player_bundle::{PlayerEntity, PlayerMark},
+        prediction::PredictionHistory,
+    },
+};
+
+// Smaller changes than these aren't worth a reducer call on their own.
+const POSITION_THRESHOLD: f32 = 0.01;
+const DIRECTION_THRESHOLD: f32 = 0.01;
+
+#[derive(Debug, Clone, PartialEq)]
+struct SentState {
+    position: Vec3,
+    direction: f32,
+    moving: bool,
+    stance: PlayerStances,
+}
+
+impl SentState {
+    fn differs_from(&self, other: &SentState) -> bool {
+        self.position.distance(other.position) > POSITION_THRESHOLD
+            || (self.direction - other.direction).abs() > DIRECTION_THRESHOLD
+            || self.moving != other.moving
+            || self.stance != other.stance
+    }
+}
+
+// Sends the local player's position and stance as one `update_player_state` call at
+// `ClientConfig::send_rate`, and only when they changed since the last send. Actions
+// have their own reducers, see player/movement.rs.
+#[derive(Resource)]
+pub struct SendScheduler {
+    timer: Timer,
+    last_sent: Option<SentState>,
+}
+
+impl FromWorld for SendScheduler {
+    fn from_world(world: &mut World) -> Self {
+        let rate = world.get_resource::<ClientConfig>().map(|c| c.send_rate).unwrap_or(20.0);
+        Self {
+            timer: Timer::from_seconds(1.0 / rate, TimerMode::Repeating),
+            last_sent: None,
+        }
+    }
+}
+
+// Reducer calls and payload bytes per second, averaged over the last second.
+#[derive(Resource, Debug)]
+pub struct SendMetrics {
+    pub calls_per_second: f32,
+    pub bytes_per_second: f32,
+    calls: u32,
+    bytes: usize,
+    window: Timer,
+}
+
+impl Default for SendMetrics {
+    fn default() -> Self {
+        Self {
+            calls_per_second: 0.0,
+            bytes_per_second: 0.0,
+            calls: 0,
+            bytes: 0,
+            window: Timer::from_seconds(1.0, TimerMode::Repeating),
+        }
+    }
+}
+
+impl SendMetrics {
+    fn record(&mut self, bytes: usize) {
+        self.calls += 1;
+        self.bytes += bytes;
+    }
+
+    fn tick(&mut self, time: &Time) {
+        self.window.tick(time.delta());
+        if !self.window.just_finished() {
+            return;
+        }
+        let seconds = self.window.duration().as_secs_f32();
+        self.calls_per_second = self.calls as f32 / seconds;
+        self.bytes_per_second = self.bytes as f32 / seconds;
+        self.calls = 0;
+        self.bytes = 0;
+        debug!(
+            "Network send: {:.1} calls/s, {:.0} bytes/s",
+            self.calls_per_second, self.bytes_per_second
+        );
+    }
+}
+
+pub fn send_player_state(
+    time: Res<Time>,
+    mut scheduler: ResMut<SendScheduler>,
+    mut metrics: ResMut<SendMetrics>,
+    mut player_q: Query<(&Transform, &PlayerEntity, &mut PredictionHistory), With<PlayerMark>>,
+) {
+    metrics.tick(&time);
+    scheduler.timer.tick(time.delta());
+    if !scheduler.timer.just_finished() {
+        return;
+    }
+    let Ok((transform, player_entity, mut history)) = player_q.get_single_mut() else {
+        return;
+    };
+    if player_entity.data.dead {
+        return;
+    }
+
+    let state = SentState {
+        position: transform.translation,
+        direction: player_entity.data.direction,
+        moving: player_entity.data.moving,
+        stance: player_entity.data.stance.clone(),
+    };
+    if scheduler.last_sent.as_ref().is_some_and(|last| !state.differs_from(last)) {
+        return;
+    }
+
+    // The position is our prediction, the server acknowledges the sequence
+    // number and `reconcile_local_player` corrects us if it disagrees.
+    let args = UpdatePlayerStateArgs {
+        position: StdbVector3 {
+            x: state.position.x,
+            y: state.position.y,
+            z: state.position.z,
+        },
+        direction: state.direction,
+        moving: state.moving,
+        stance: state.stance.clone(),
+        // Clears whatever action the server set last, see `update_player_state`.
+        action: PlayerAction::None,
+        sequence: history.record(state.position),
+    };
+    metrics.record(bsatn::to_vec(&args).map(|bytes| bytes.len()).unwrap_or(0));
+    args.invoke();
+
+    scheduler.last_sent = Some(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing() -> SentState {
+        SentState {
+            position: Vec3::ZERO,
+            direction: 0.0,
+            moving: false,
+            stance: PlayerStances::NonCombat,
+        }
+    }
+
+    #[test]
+    fn changes_below_the_thresholds_are_not_sent() {
+        let next = SentState {
+            position: Vec3::new(POSITION_THRESHOLD / 2.0, 0.0, 0.0),
+            direction: DIRECTION_THRESHOLD / 2.0,
+            ..standing()
+        };
+        assert!(!next.differs_from(&standing()));
+    }
+
+    #[test]
+    fn position_and_direction_past_the_thresholds_are_sent() {
+        let moved = SentState {
+            position: Vec3::new(0.0, 0.0, POSITION_THRESHOLD * 2.0),
+            ..standing()
+        };
+        let turned = SentState {
+            direction: -DIRECTION_THRESHOLD * 2.0,
+            ..standing()
+        };
+        assert!(moved.differs_from(&standing()));
+        assert!(turned.differs_from(&standing()));
+    }
+
+    #[test]
+    fn moving_and_stance_changes_are_always_sent() {
+        let started = SentState { moving: true, ..standing() };
+        let stance = SentState {
+            stance: PlayerStances::Combat,
+            ..standing()
+        };
+        assert!(started.differs_from(&standing()));
+        assert!(stance.differs_from(&standing()));
+    }
+}
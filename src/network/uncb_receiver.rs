@@ -4,7 +4,7 @@ use futures_channel::mpsc;
 use log::info;
 use spacetimedb_sdk::{identity::Credentials, Address};
 
-use crate::module_bindings::{EntityComponent, PlayerComponent, ReducerEvent};
+use crate::module_bindings::{ChatMessage, CombatEvent, EntityComponent, Equipment, PlayerComponent, ReducerEvent, SpellCast, SpellEntity, WorldItem};
 
 
 /// Unbound Callback Message
@@ -20,6 +20,14 @@ pub enum UncbMessage {
         address: Address,
     },
     Disconnected,
+    SubscriptionApplied,
+    CharacterCreated,
+    CharacterRejected {
+        reason: String,
+    },
+    JoinRejected {
+        reason: String,
+    },
     PlayerInserted {
         data: PlayerComponent,
         event: Option<ReducerEvent>,
@@ -31,6 +39,7 @@ pub enum UncbMessage {
     },
     PlayerRemoved {
         data: PlayerComponent,
+        event: Option<ReducerEvent>,
     },
     EntityInserted {
         data: EntityComponent,
@@ -43,7 +52,45 @@ pub enum UncbMessage {
     },
     EntityRemoved {
         data: EntityComponent,
-        event: ReducerEvent,
+        event: Option<ReducerEvent>,
+    },
+    CombatEventInserted {
+        data: CombatEvent,
+    },
+    InventoryChanged,
+    WorldItemInserted {
+        data: WorldItem,
+    },
+    WorldItemRemoved {
+        data: WorldItem,
+    },
+    EquipmentChanged {
+        data: Equipment,
+    },
+    SpellCastInserted {
+        data: SpellCast,
+    },
+    SpellCastRemoved {
+        data: SpellCast,
+    },
+    SpellEntityInserted {
+        data: SpellEntity,
+    },
+    SpellEntityRemoved {
+        data: SpellEntity,
+    },
+    ChatMessageInserted {
+        data: ChatMessage,
+    },
+    ChatRejected {
+        reason: String,
+    },
+    // One of the admin reducers called from a chat command failed.
+    CommandRejected {
+        reason: String,
+    },
+    RespawnRejected {
+        reason: String,
     },
 }
 
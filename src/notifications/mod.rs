@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+// How long a notification stays up, the last part of it fading out.
+const NOTIFICATION_LIFETIME: f32 = 4.0;
+const NOTIFICATION_FADE: f32 = 1.0;
+// Older notifications are dropped once this many are showing.
+const MAX_NOTIFICATIONS: usize = 5;
+
+pub struct NotificationsPlugin;
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<Notify>()
+            .add_systems(Startup, spawn_notification_list)
+            .add_systems(Update, (show_notifications, update_notifications).chain());
+    }
+}
+
+// Short message shown at the top of the screen, e.g. "Bob left the area".
+#[derive(Event, Debug, Clone)]
+pub struct Notify(pub String);
+
+#[derive(Component)]
+pub struct NotificationList;
+
+#[derive(Component)]
+pub struct Notification {
+    pub timer: Timer,
+}
+
+fn spawn_notification_list(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            z_index: ZIndex::Global(50),
+            ..default()
+        },
+        NotificationList,
+    ));
+}
+
+pub fn show_notifications(
+    mut commands: Commands,
+    mut events: EventReader<Notify>,
+    list_q: Query<(Entity, Option<&Children>), With<NotificationList>>,
+) {
+    let Ok((list, children)) = list_q.get_single() else {
+        return;
+    };
+    let messages: Vec<String> = events.read().map(|Notify(message)| message.clone()).collect();
+    if messages.is_empty() {
+        return;
+    }
+
+    if let Some(children) = children {
+        let excess = (children.len() + messages.len()).saturating_sub(MAX_NOTIFICATIONS);
+        for oldest in children.iter().take(excess) {
+            commands.entity(*oldest).despawn_recursive();
+        }
+    }
+
+    let skip = messages.len().saturating_sub(MAX_NOTIFICATIONS);
+    for message in messages.into_iter().skip(skip) {
+        let notification = commands
+            .spawn((
+                TextBundle::from_section(message, TextStyle { font_size: 20.0, ..default() }),
+                Notification {
+                    timer: Timer::from_seconds(NOTIFICATION_LIFETIME, TimerMode::Once),
+                },
+            ))
+            .id();
+        commands.entity(list).add_child(notification);
+    }
+}
+
+pub fn update_notifications(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notification_q: Query<(Entity, &mut Notification, &mut Text)>,
+) {
+    for (entity, mut notification, mut text) in notification_q.iter_mut() {
+        notification.timer.tick(time.delta());
+        if notification.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let remaining = notification.timer.remaining_secs();
+        text.sections[0].style.color.set_alpha((remaining / NOTIFICATION_FADE).min(1.0));
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::{prelude::*, utils::HashSet};
+use bevy_rapier3d::prelude::{Collider, CollisionGroups, Group, RigidBody};
+
+use crate::{
+    animations::setup_animations::{AnimationController, GraphAnimations},
+    asset_manager::GameAssets,
+    config::ClientConfig,
+    module_bindings::{EntityComponent, Npc},
+    network::{entity_map::NetworkEntityMap, uncb_receiver::{UncbEvent, UncbMessage}},
+    player::{
+        departure::Departing,
+        interpolation::{Snapshot, SnapshotBuffer},
+        player_bundle::{PlayerEntity, PlayerSceneHandle, ENVIRONMENT_GROUP, PLAYER_GROUP},
+        HandleScenesState,
+    },
+};
+
+pub struct NpcPlugin;
+impl Plugin for NpcPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(HandleScenesState::Done), spawn_cached_npcs)
+            .add_systems(Update, (spawn_npcs, despawn_npcs).run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+// Marks the scene of an NPC. Its `EntityComponent` row is kept in `PlayerEntity`
+// like a remote player's, so updates, interpolation and animations treat both alike.
+#[derive(Component, Debug, Clone)]
+pub struct NpcMark {
+    pub data: Npc,
+}
+
+#[derive(Bundle)]
+pub struct NpcBundle {
+    pub scene: SceneBundle,
+    pub npc: NpcMark,
+    pub npc_entity: PlayerEntity,
+    pub snapshots: SnapshotBuffer,
+    pub animation_controller: AnimationController,
+    pub collider: Collider,
+    pub collision_group: CollisionGroups,
+    // Moved by the server, physics only keeps players from walking through it.
+    pub body_type: RigidBody,
+}
+
+impl NpcBundle {
+    pub fn new(
+        npc: Npc,
+        entity: EntityComponent,
+        ga: &Res<GameAssets>,
+        gltf_assets: &Res<Assets<Gltf>>,
+        graph: &Res<GraphAnimations>,
+        time: &Res<Time>,
+    ) -> Self {
+        let position = Vec3::new(entity.position.x, entity.position.y, entity.position.z);
+        Self {
+            scene: SceneBundle {
+                scene: PlayerSceneHandle::new(ga, gltf_assets).player_scene,
+                transform: Transform::from_translation(position)
+                    .with_rotation(Quat::from_rotation_y(entity.direction)),
+                ..default()
+            },
+            snapshots: SnapshotBuffer::new(Snapshot {
+                time: time.elapsed_seconds_f64(),
+                position,
+                direction: entity.direction,
+                moving: entity.moving,
+            }),
+            npc: NpcMark { data: npc },
+            npc_entity: PlayerEntity { data: entity },
+            animation_controller: AnimationController::new(GraphAnimations {
+                index_node: graph.index_node.clone(),
+                graph: graph.graph.clone(),
+            }),
+            collider: Collider::capsule_y(0.40 / 2.0, 0.07 / 2.0),
+            collision_group: CollisionGroups::new(Group::from_bits_truncate(PLAYER_GROUP), Group::from_bits_truncate(ENVIRONMENT_GROUP)),
+            body_type: RigidBody::KinematicPositionBased,
+        }
+    }
+}
+
+// Spawns the NPCs whose rows were already in the cache before the world was up,
+// their inserts and the first subscription came and went while we were loading.
+pub fn spawn_cached_npcs(
+    mut commands: Commands,
+    npc_q: Query<&NpcMark>,
+    ga: Res<GameAssets>,
+    gltf_assets: Res<Assets<Gltf>>,
+    graph: Res<GraphAnimations>,
+    time: Res<Time>,
+) {
+    // Entering `Done` again after a new player spawns must not double them up.
+    let spawned: HashSet<u64> = npc_q.iter().map(|npc| npc.data.entity_id).collect();
+    for npc in Npc::iter() {
+        if spawned.contains(&npc.entity_id) {
+            continue;
+        }
+        let Some(entity) = EntityComponent::find_by_entity_id(npc.entity_id) else {
+            continue;
+        };
+        info!("Spawning npc {} at {:?}", npc.entity_id, entity.position);
+        commands.spawn(NpcBundle::new(npc, entity, &ga, &gltf_assets, &graph, &time));
+    }
+}
+
+// NPC rows are received in full, their entities only for the cells around us,
+// see network/interest.rs. A scene is spawned once both are in the cache: when
+// the entity comes into one of our cells, or for all of them after a subscription.
+pub fn spawn_npcs(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    entity_map: Res<NetworkEntityMap>,
+    npc_q: Query<&NpcMark>,
+    ga: Res<GameAssets>,
+    gltf_assets: Res<Assets<Gltf>>,
+    graph: Res<GraphAnimations>,
+    time: Res<Time>,
+) {
+    let mut candidates: HashSet<u64> = HashSet::new();
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::EntityInserted { data, .. } => {
+                candidates.insert(data.entity_id);
+            }
+            UncbMessage::SubscriptionApplied => {
+                candidates.extend(Npc::iter().map(|npc| npc.entity_id));
+            }
+            _ => {}
+        }
+    }
+
+    if candidates.is_empty() {
+        return;
+    }
+    // `entity_map` only catches up in PreUpdate, so also skip what `spawn_cached_npcs`
+    // spawned this frame.
+    let spawned: HashSet<u64> = npc_q.iter().map(|npc| npc.data.entity_id).collect();
+    for entity_id in candidates {
+        if entity_map.contains(entity_id) || spawned.contains(&entity_id) {
+            continue;
+        }
+        let (Some(npc), Some(entity)) = (Npc::find_by_entity_id(entity_id), EntityComponent::find_by_entity_id(entity_id)) else {
+            continue;
+        };
+        info!("Spawning npc {} at {:?}", entity_id, entity.position);
+        commands.spawn(NpcBundle::new(npc, entity, &ga, &gltf_assets, &graph, &time));
+    }
+}
+
+// NPCs that walked out of our cells are despawned and come back through
+// `spawn_npcs`. Ones the server removed, after dying, fade out like departing players.
+pub fn despawn_npcs(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    entity_map: Res<NetworkEntityMap>,
+    config: Res<ClientConfig>,
+    npc_q: Query<(Entity, &NpcMark), Without<Departing>>,
+) {
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::EntityRemoved { data, .. } => {
+                let Some(entity) = entity_map.get(data.entity_id).filter(|e| npc_q.contains(*e)) else {
+                    continue;
+                };
+                if Npc::find_by_entity_id(data.entity_id).is_none() && config.departure_fade > 0.0 {
+                    commands
+                        .entity(entity)
+                        .remove::<(NpcMark, SnapshotBuffer)>()
+                        .insert(Departing::new(config.departure_fade));
+                } else {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            // Whatever went away while we weren't subscribed, e.g. during a reconnect.
+            UncbMessage::SubscriptionApplied => {
+                for (entity, npc) in npc_q.iter() {
+                    let entity_id = npc.data.entity_id;
+                    if Npc::find_by_entity_id(entity_id).is_none() || EntityComponent::find_by_entity_id(entity_id).is_none() {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+// A remote player that left the world. It no longer takes part in replication
+// and shrinks away over the timer before being despawned.
+#[derive(Component)]
+pub struct Departing {
+    pub timer: Timer,
+}
+
+impl Departing {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+pub fn fade_out_departing(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut departing_q: Query<(Entity, &mut Departing, &mut Transform)>,
+) {
+    for (entity, mut departing, mut transform) in departing_q.iter_mut() {
+        departing.timer.tick(time.delta());
+        if departing.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            transform.scale = Vec3::splat(departing.timer.fraction_remaining());
+        }
+    }
+}
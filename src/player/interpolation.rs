@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::config::ClientConfig;
+
+// Plenty for any sane delay at the server's update rate.
+const BUFFER_SIZE: usize = 32;
+// How far past the newest snapshot a moving player is carried forward before we
+// stop guessing and wait for the next update.
+const MAX_EXTRAPOLATION: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Snapshot {
+    // Local time the update arrived, in seconds since startup.
+    pub time: f64,
+    pub position: Vec3,
+    pub direction: f32,
+    pub moving: bool,
+}
+
+impl Snapshot {
+    fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.direction)
+    }
+}
+
+// Recent server states of a remote player. They're rendered
+// `ClientConfig::interpolation_delay` in the past so there is almost always a
+// snapshot on each side of the time being shown.
+#[derive(Component, Debug, Default)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<Snapshot>,
+}
+
+impl SnapshotBuffer {
+    pub fn new(snapshot: Snapshot) -> Self {
+        Self {
+            snapshots: VecDeque::from([snapshot]),
+        }
+    }
+
+    // Adds a snapshot unless it's the same state as the newest one. `EntityUpdated`
+    // also fires for health or stance changes, which don't move the entity.
+    pub fn push(&mut self, snapshot: Snapshot) {
+        if let Some(last) = self.snapshots.back() {
+            if last.position == snapshot.position
+                && last.direction == snapshot.direction
+                && last.moving == snapshot.moving
+            {
+                return;
+            }
+        }
+        if self.snapshots.len() == BUFFER_SIZE {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    // Where the player was at `time`. Between two snapshots the position is lerped
+    // and the facing slerped, past the newest one a moving player keeps going at
+    // its last known velocity for up to `MAX_EXTRAPOLATION`.
+    pub fn sample(&self, time: f64) -> Option<(Vec3, Quat)> {
+        let last = self.snapshots.back()?;
+
+        if time >= last.time {
+            let previous = self.snapshots.iter().rev().nth(1);
+            return Some(match previous {
+                Some(previous) if last.moving && last.time > previous.time => {
+                    let velocity = (last.position - previous.position) / (last.time - previous.time) as f32;
+                    let ahead = (time - last.time).min(MAX_EXTRAPOLATION) as f32;
+                    (last.position + velocity * ahead, last.rotation())
+                }
+                _ => (last.position, last.rotation()),
+            });
+        }
+
+        let after = self.snapshots.iter().position(|s| s.time > time)?;
+        if after == 0 {
+            let first = &self.snapshots[0];
+            return Some((first.position, first.rotation()));
+        }
+        let (from, to) = (&self.snapshots[after - 1], &self.snapshots[after]);
+        let t = ((time - from.time) / (to.time - from.time)) as f32;
+        Some((
+            from.position.lerp(to.position, t),
+            from.rotation().slerp(to.rotation(), t),
+        ))
+    }
+
+    // Drops snapshots that are entirely behind `time`, keeping the one we're
+    // interpolating from.
+    fn discard_before(&mut self, time: f64) {
+        while self.snapshots.len() > 2 && self.snapshots[1].time <= time {
+            self.snapshots.pop_front();
+        }
+    }
+}
+
+pub fn interpolate_positions(
+    time: Res<Time>,
+    config: Res<ClientConfig>,
+    mut query: Query<(&mut Transform, &mut SnapshotBuffer)>,
+) {
+    let render_time = time.elapsed_seconds_f64() - config.interpolation_delay as f64;
+    for (mut transform, mut buffer) in query.iter_mut() {
+        if let Some((position, rotation)) = buffer.sample(render_time) {
+            transform.translation = position;
+            transform.rotation = rotation;
+        }
+        buffer.discard_before(render_time);
+    }
+}
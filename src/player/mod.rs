@@ -1,15 +1,21 @@
 pub mod player_bundle;
 pub mod spawn_player;
-mod movement;
+pub mod movement;
+pub mod interpolation;
+pub mod departure;
+pub mod prediction;
 //mod player_updates;
 
-use movement::{interpolate_positions, player_movement};
+use departure::fade_out_departing;
+use interpolation::interpolate_positions;
+use movement::player_movement;
+use prediction::reconcile_local_player;
 use player_bundle::{Player, PlayerEntity};
 use bevy::prelude::*;
 use spawn_player::{spawn_new_players, spawn_players};
 use super::module_bindings;
 
-use crate::{asset_manager::AssetLoadingState, module_bindings::{create_player, Client}, GameState};
+use crate::{asset_manager::AssetLoadingState, module_bindings::Client, network::{player_updates::handle_player_updates, reconnect::ConnectionState, send_scheduler::send_player_state}, GameState};
 
 #[derive(States, Clone, Eq, PartialEq, Hash, Default, Debug)]
 pub enum HandleScenesState {
@@ -24,11 +30,16 @@ impl Plugin for HandleScenesPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_state::<HandleScenesState>()
-            .add_systems(Startup, create_player)
             .add_systems(OnEnter(AssetLoadingState::Spawning), spawn_players)
             .add_systems(Update, log_transitions)
-            .add_systems(Update, (player_movement, interpolate_positions).run_if(in_state(HandleScenesState::Done)))
-            .add_systems(Update, spawn_new_players.run_if(in_state(HandleScenesState::Done)));
+            .add_systems(Update, (
+                reconcile_local_player,
+                player_movement,
+                send_player_state.run_if(in_state(ConnectionState::Connected)),
+                handle_player_updates,
+                interpolate_positions,
+            ).chain().run_if(in_state(HandleScenesState::Done)))
+            .add_systems(Update, (spawn_new_players, fade_out_departing).run_if(in_state(HandleScenesState::Done)));
 
             
     }
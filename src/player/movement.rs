@@ -1,138 +1,122 @@
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
 
-use crate::{input::{get_input_vector, handle_attack, handle_stance_change, GameActions}, module_bindings::{entity_component, update_player_action, update_player_position, update_player_stance, StdbVector3}, network::{uncb_receiver::{UncbEvent, UncbMessage}, vec3_nan_to_zero}};
+use crate::{input::{get_input_vector, handle_action, handle_stance_change, GameActions}, module_bindings::{attack, block, cast_spell, dodge, Config, PlayerAction, PlayerStances}, network::{uncb_receiver::{UncbEvent, UncbMessage}, vec3_nan_to_zero}};
 
 use super::{player_bundle::{Player, PlayerEntity}, spawn_player::PlayerEntities};
 
+// Walk speed until the server's `Config` row has arrived.
+const DEFAULT_PLAYER_SPEED: f32 = 2.0;
+
+// Moves the local player from input. Remote players are driven by
+// `handle_player_updates` and `interpolate_positions` instead.
 pub fn player_movement(
     mut player_q: ParamSet<
         (
             Query<(
-                Option<&ActionState<GameActions>>,
+                &ActionState<GameActions>,
                 &mut Transform,
                 &mut PlayerEntity,
-                Option<&mut InterpolatedTransform>
         ), With<Player>>,
     )>,
     cam_q: Query<&Transform, (With<Camera3d>, Without<Player>)>,
     time: Res<Time>
 ) {
-    for (action_state, mut transform, mut player_entity, interpolated_transform) in 
+    // Walking at the server's cap keeps us inside what `validate_move` accepts.
+    let base_speed = Config::find_by_version(0).map_or(DEFAULT_PLAYER_SPEED, |config| config.max_speed);
+    for (action_state, mut transform, mut player_entity) in 
     player_q.p0().iter_mut() {
+        // The server rejects input from the dead until they respawn.
+        if player_entity.data.dead {
+            player_entity.data.moving = false;
+            continue;
+        }
 
-        let player_speed = 2.0;
+        let player_speed = base_speed * stance_speed_multiplier(&player_entity.data.stance);
         let mut is_moving = false;
         let mut new_direction = player_entity.data.direction;
 
-        if let Some(action_state) = action_state {
-    
-            let input_vector = vec3_nan_to_zero(
-                get_input_vector(
-                    action_state, 
-                    &cam_q,
-                    //&mut entity_query,
-                ).normalize_or_zero()) * time.delta_seconds();
-                
-            let mut horizontal_movement = input_vector * player_speed;
-            let _vertical_movement = if action_state.pressed(&GameActions::Jump) {
-                player_speed * time.delta_seconds()
-            } else {
-                0.0
-            };
-
-            if horizontal_movement.length_squared() > 0.0 {
-                is_moving = true;
-                horizontal_movement.y = 0.0;
-                
-                // Correcting direction calculation using atan2
-                new_direction = f32::atan2(horizontal_movement.x * -1.0, horizontal_movement.z * -1.0);
-
-                transform.look_to(horizontal_movement.normalize(), Vec3::Y);
-            }
-
-            transform.translation.x += horizontal_movement.x;
-            transform.translation.z += horizontal_movement.z;
-
-            // Jump - Movement along the Y axis requires special attention.
-            if action_state.pressed(&GameActions::Jump) {
-                transform.translation.y += player_speed * time.delta_seconds();
-            }
-
-            let new_stance = handle_stance_change(action_state, &mut player_entity);
-            let new_action = handle_attack(action_state, &mut player_entity);
-
-            // Sync to the database.
-            update_player_position(StdbVector3 {
-                x: transform.translation.x,
-                y: transform.translation.y,
-                z: transform.translation.z,
-            },
-                new_direction,
-                is_moving,
-            );
-            update_player_stance(new_stance.clone());
-            update_player_action(new_action.clone());
-            // Update player entity direction and stance
-            player_entity.data.stance = new_stance;
-            player_entity.data.direction = new_direction;
+        let input_vector = vec3_nan_to_zero(
+            get_input_vector(
+                action_state, 
+                &cam_q,
+                //&mut entity_query,
+            ).normalize_or_zero()) * time.delta_seconds();
+            
+        let mut horizontal_movement = input_vector * player_speed;
+        let _vertical_movement = if action_state.pressed(&GameActions::Jump) {
+            player_speed * time.delta_seconds()
         } else {
-            // Read from the database and update transform.
-            if let Some(entity) = entity_component::EntityComponent::filter_by_entity_id(player_entity.data.entity_id).next() {
-                let position = entity.position;
-                let direction = entity.direction;
-                let stance = entity.stance;
-                let action = entity.action;
-
-                if let Some(mut interpolated_transform) = interpolated_transform {
-                    interpolated_transform.target_position = Vec3::new(position.x, position.y, position.z);
-                    interpolated_transform.progress = 0.0;
-                } else {
-                    transform.translation = Vec3::new(position.x, position.y, position.z);
-                }
-
-                // Apply the stored direction
-                transform.rotation = Quat::from_rotation_y(direction);
-
-                // Ensure player entity is updated from the database
-                player_entity.data.position = position.clone();
-                player_entity.data.direction = direction.clone();
-                player_entity.data.stance = stance.clone();
-                player_entity.data.action = action.clone();
-                //println!("Player entity's stance: {:?}", player_entity.data.stance);
-            } else {
-                warn!("EntityComponent not found for entity_id: {:?}", player_entity.data.entity_id);
-            }
+            0.0
+        };
+
+        if horizontal_movement.length_squared() > 0.0 {
+            is_moving = true;
+            horizontal_movement.y = 0.0;
+            
+            // Correcting direction calculation using atan2
+            new_direction = f32::atan2(horizontal_movement.x * -1.0, horizontal_movement.z * -1.0);
+
+            transform.look_to(horizontal_movement.normalize(), Vec3::Y);
         }
+
+        transform.translation.x += horizontal_movement.x;
+        transform.translation.z += horizontal_movement.z;
+
+        // Jump - Movement along the Y axis requires special attention.
+        if action_state.pressed(&GameActions::Jump) {
+            transform.translation.y += player_speed * time.delta_seconds();
+        }
+
+        let new_stance = handle_stance_change(action_state, &mut player_entity);
+        let new_action = handle_action(action_state, &mut player_entity);
+        // Hits, blocks, dodges and spells are resolved on the server, see CombatEvent.
+        match new_action {
+            PlayerAction::Attack => attack(),
+            PlayerAction::Block => block(),
+            PlayerAction::Dodge => dodge(),
+            PlayerAction::CastSpell(spell_id) => cast_spell(spell_id),
+            _ => {}
+        }
+
+        // Direction and stance are synced to the database by `send_player_state`
+        // at a fixed rate, see network/send_scheduler.rs.
+        player_entity.data.stance = new_stance;
+        player_entity.data.direction = new_direction;
+        player_entity.data.moving = is_moving;
     }
 }
 
 
 
-pub fn interpolate_positions(
-    time: Res<Time>,
-    mut query: Query<(&mut Transform, &mut InterpolatedTransform)>,
-) {
-    for (mut transform, mut interpolated_transform) in query.iter_mut() {
-        let progress = (interpolated_transform.progress + time.delta_seconds()).min(1.0);
-        transform.translation = transform
-            .translation
-            .lerp(interpolated_transform.target_position, progress);
-        interpolated_transform.progress = progress;
+// Mirrors `PlayerStances::movement_speed_multiplier` in the server module, the
+// server clamps anything faster.
+pub fn stance_speed_multiplier(stance: &PlayerStances) -> f32 {
+    match stance {
+        PlayerStances::NonCombat => 1.0,
+        PlayerStances::Combat => 0.85,
+        PlayerStances::Precise => 0.7,
+        PlayerStances::Defensive => 0.6,
     }
 }
 
-#[derive(Component)]
-pub struct InterpolatedTransform {
-    pub target_position: Vec3,
-    pub progress: f32,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl Default for InterpolatedTransform {
-    fn default() -> Self {
-        Self {
-            target_position: Vec3::ZERO,
-            progress: 1.0,
+    #[test]
+    fn multipliers_match_the_server() {
+        assert_eq!(stance_speed_multiplier(&PlayerStances::NonCombat), 1.0);
+        assert_eq!(stance_speed_multiplier(&PlayerStances::Combat), 0.85);
+        assert_eq!(stance_speed_multiplier(&PlayerStances::Precise), 0.7);
+        assert_eq!(stance_speed_multiplier(&PlayerStances::Defensive), 0.6);
+    }
+
+    #[test]
+    fn fighting_stances_are_slower() {
+        let walking = stance_speed_multiplier(&PlayerStances::NonCombat);
+        for stance in [PlayerStances::Combat, PlayerStances::Precise, PlayerStances::Defensive] {
+            assert!(stance_speed_multiplier(&stance) < walking, "{:?}", stance);
         }
     }
-}
\ No newline at end of file
+}
@@ -6,8 +6,8 @@ use spacetimedb_sdk::identity::Identity;
 
 use crate::{animations::{run_animations, setup_animations::AnimationController}, asset_manager::GameAssets, input::GameActions, module_bindings::{EntityComponent, PlayerComponent}, network::player_connected::PlayerDataResource};
 
-const PLAYER_GROUP: u32 = 0b01;
-const ENVIRONMENT_GROUP: u32 = 0b10;
+pub const PLAYER_GROUP: u32 = 0b01;
+pub const ENVIRONMENT_GROUP: u32 = 0b10;
 
 #[derive(Component, Debug, Clone)]
 // pub struct Player  {
@@ -22,8 +22,8 @@ pub enum PlayerStances {
     #[default]
     NonCombat,
     Combat, // Normal combat state
-    // Precise,
-    // Defensive,
+    Precise,
+    Defensive,
 }
 
 #[derive(Component)]
@@ -84,11 +84,15 @@ impl PlayerBundle {
         gltf_assets: &Res<Assets<Gltf>>,
     ) -> Self {
         let player_scene = PlayerSceneHandle::new(ga, gltf_assets);
+        // Characters persist between sessions, so spawn where the entity was last saved.
+        // New characters start at the origin, keep them above the floor.
+        let position = &player_entity.data.position;
         
         Self {
             player_scene: SceneBundle {
                 scene: player_scene.player_scene.clone(),
-                transform: Transform::from_xyz(0.0, 0.245, 0.0),
+                transform: Transform::from_xyz(position.x, position.y.max(0.245), position.z)
+                    .with_rotation(Quat::from_rotation_y(player_entity.data.direction)),
                 ..Default::default()
             },
             player: Player {
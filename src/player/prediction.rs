@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{
+    module_bindings::ReducerEvent,
+    network::uncb_receiver::{UncbEvent, UncbMessage},
+};
+
+use super::player_bundle::{PlayerEntity, PlayerMark};
+
+// Several seconds of sends, older inputs can't be replayed anymore.
+const HISTORY_SIZE: usize = 128;
+// Server positions closer than this to the prediction are taken as agreeing.
+const CORRECTION_THRESHOLD: f32 = 0.01;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PredictedState {
+    pub sequence: u32,
+    pub position: Vec3,
+}
+
+// The positions the local player sent to the server, keyed by the sequence
+// number of the `update_player_state` call that carried them.
+#[derive(Component, Debug)]
+pub struct PredictionHistory {
+    next_sequence: u32,
+    states: VecDeque<PredictedState>,
+}
+
+impl Default for PredictionHistory {
+    fn default() -> Self {
+        Self {
+            // The server starts at 0 and only applies newer sequences.
+            next_sequence: 1,
+            states: VecDeque::with_capacity(HISTORY_SIZE),
+        }
+    }
+}
+
+impl PredictionHistory {
+    // Stores a predicted position and returns the sequence number to send with it.
+    pub fn record(&mut self, position: Vec3) -> u32 {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        if self.states.len() == HISTORY_SIZE {
+            self.states.pop_front();
+        }
+        self.states.push_back(PredictedState { sequence, position });
+        sequence
+    }
+
+    // The server moved us itself, e.g. a teleport, and skipped `input_sequence`
+    // past everything we sent. Those inputs are void, we continue after `ack`.
+    pub fn is_server_move(&self, ack: u32) -> bool {
+        ack >= self.next_sequence
+    }
+
+    pub fn restart_after(&mut self, ack: u32) {
+        self.next_sequence = ack + 1;
+        self.states.clear();
+    }
+
+    // Drops everything the server has acknowledged up to `ack`. If the server ended
+    // up somewhere else than we predicted for that input, replays the moves made
+    // since then on top of its position and returns where we should be now.
+    pub fn reconcile(&mut self, ack: u32, server_position: Vec3, current: Vec3) -> Option<Vec3> {
+        let predicted = self.states.iter().find(|state| state.sequence == ack)?.position;
+        self.states.retain(|state| state.sequence > ack);
+
+        if server_position.distance(predicted) <= CORRECTION_THRESHOLD {
+            return None;
+        }
+
+        let mut position = server_position;
+        let mut previous = predicted;
+        for state in self.states.iter_mut() {
+            position += state.position - previous;
+            previous = state.position;
+            state.position = position;
+        }
+        // Whatever moved us since the last send, e.g. physics this frame.
+        Some(position + (current - previous))
+    }
+}
+
+pub fn reconcile_local_player(
+    mut event_reader: EventReader<UncbEvent>,
+    mut player_q: Query<(&mut PlayerEntity, &mut PredictionHistory, &mut Transform), With<PlayerMark>>,
+) {
+    let Ok((mut player_entity, mut history, mut transform)) = player_q.get_single_mut() else {
+        return;
+    };
+
+    for event in event_reader.read() {
+        let UncbMessage::EntityUpdated { new, event, .. } = &event.message else {
+            continue;
+        };
+        if new.entity_id != player_entity.data.entity_id {
+            continue;
+        }
+        // Only the server kills and revives, see death/mod.rs.
+        player_entity.data.dead = new.dead;
+        // Our stance is otherwise ours to pick, but one set by an admin has to stick
+        // or our next `update_player_state` would undo it.
+        if let Some(ReducerEvent::SetPlayerStance(_)) = event {
+            player_entity.data.stance = new.stance.clone();
+        }
+
+        let server_position = Vec3::new(new.position.x, new.position.y, new.position.z);
+        if history.is_server_move(new.input_sequence) {
+            info!("Moved by the server to {:?}", server_position);
+            history.restart_after(new.input_sequence);
+            transform.translation = server_position;
+            continue;
+        }
+        if let Some(corrected) = history.reconcile(new.input_sequence, server_position, transform.translation) {
+            warn!(
+                "Server corrected input {}: {:?} -> {:?}",
+                new.input_sequence, transform.translation, corrected
+            );
+            transform.translation = corrected;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: Vec3, expected: Vec3) {
+        assert!(actual.distance(expected) < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn sequences_start_after_the_servers_initial_zero() {
+        let mut history = PredictionHistory::default();
+        assert_eq!(history.record(Vec3::ZERO), 1);
+        assert_eq!(history.record(Vec3::X), 2);
+    }
+
+    #[test]
+    fn agreeing_server_position_needs_no_correction() {
+        let mut history = PredictionHistory::default();
+        let ack = history.record(Vec3::X);
+        history.record(Vec3::X * 2.0);
+
+        assert_eq!(history.reconcile(ack, Vec3::X, Vec3::X * 2.0), None);
+        // Acknowledged inputs are gone.
+        assert_eq!(history.reconcile(ack, Vec3::ZERO, Vec3::X * 2.0), None);
+    }
+
+    #[test]
+    fn correction_replays_later_inputs() {
+        let mut history = PredictionHistory::default();
+        let ack = history.record(Vec3::new(1.0, 0.0, 0.0));
+        let next = history.record(Vec3::new(2.0, 0.0, 0.0));
+        history.record(Vec3::new(3.0, 0.0, 0.0));
+
+        // The server only let the first input get halfway, and physics moved us a
+        // little since the last send.
+        let corrected = history.reconcile(ack, Vec3::new(0.5, 0.0, 0.0), Vec3::new(3.2, 0.0, 0.0));
+        assert_near(corrected.unwrap(), Vec3::new(2.7, 0.0, 0.0));
+
+        // The replayed inputs were rewritten, so the server agreeing with them later
+        // doesn't correct us a second time.
+        assert_eq!(history.reconcile(next, Vec3::new(1.5, 0.0, 0.0), Vec3::new(2.7, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn unknown_sequences_are_ignored() {
+        let mut history = PredictionHistory::default();
+        history.record(Vec3::X);
+        assert_eq!(history.reconcile(42, Vec3::ZERO, Vec3::X), None);
+    }
+
+    #[test]
+    fn old_inputs_fall_out_of_the_history() {
+        let mut history = PredictionHistory::default();
+        let first = history.record(Vec3::ZERO);
+        for _ in 0..HISTORY_SIZE {
+            history.record(Vec3::X);
+        }
+        assert_eq!(history.reconcile(first, Vec3::Y, Vec3::X), None);
+    }
+
+    #[test]
+    fn server_moves_restart_the_sequence() {
+        let mut history = PredictionHistory::default();
+        history.record(Vec3::X);
+        assert!(!history.is_server_move(1));
+        assert!(history.is_server_move(1025));
+
+        history.restart_after(1025);
+        assert_eq!(history.reconcile(1, Vec3::ZERO, Vec3::X), None);
+        assert_eq!(history.record(Vec3::X), 1026);
+    }
+}
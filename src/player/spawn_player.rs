@@ -1,9 +1,9 @@
 use bevy::{prelude::*, utils::HashMap};
 use bevy_third_person_camera::ThirdPersonCameraTarget;
 use leafwing_input_manager::{prelude::{ActionState, InputMap}, InputManagerBundle};
-use crate::{animations::{self, setup_animations::{AnimationController, Animations, GraphAnimations}}, asset_manager::GameAssets, input::GameActions, module_bindings::{create_player, entity_component, PlayerStances}, network::{player_connected::{NewPlayer, PlayerDataResource}, uncb_receiver::UncbEvent}};
+use crate::{animations::{self, setup_animations::{AnimationController, Animations, GraphAnimations}}, asset_manager::GameAssets, input::GameActions, module_bindings::{create_player, entity_component, PlayerStances}, network::{entity_map::NetworkEntityMap, player_connected::{NewPlayer, PlayerDataResource}, uncb_receiver::UncbEvent}};
 
-use super::{player_bundle::{Player, PlayerBundle, PlayerEntity, PlayerMark, PlayerSceneHandle}, HandleScenesState};
+use super::{interpolation::{Snapshot, SnapshotBuffer}, player_bundle::{Player, PlayerBundle, PlayerEntity, PlayerMark, PlayerSceneHandle}, prediction::PredictionHistory, HandleScenesState};
 
 
 #[derive(Resource, Debug)]
@@ -32,12 +32,14 @@ pub fn setup_player(
     ga: &Res<GameAssets>,
     gltf_assets: &Res<Assets<Gltf>>,
     graph: &Res<GraphAnimations>,
+    time: &Res<Time>,
     //assets: &Res<SceneAssets>,
     
 ) {
     
     let mut scene_entities: HashMap<String, Entity> = HashMap::new();
     let player_bundle = PlayerBundle::new(player, player_entity, ga, gltf_assets);
+    let spawn_position = player_bundle.player_scene.transform.translation;
 
 
     if is_main_player {
@@ -49,6 +51,7 @@ pub fn setup_player(
             graph: graph.graph.clone(),
         }))
         .insert(PlayerMark)
+        .insert(PredictionHistory::default())
         // .insert(controller)
         .insert(InputManagerBundle::<GameActions> {
             action_state: ActionState::default(),
@@ -60,7 +63,21 @@ pub fn setup_player(
                 (GameActions::Jump,     KeyCode::Space),
                 (GameActions::Crouch,   KeyCode::ControlLeft),
                 (GameActions::CombatStance, KeyCode::KeyA),
-                (GameActions::Attack, KeyCode::Digit1)
+                (GameActions::CycleStance, KeyCode::KeyQ),
+                (GameActions::Attack, KeyCode::Digit1),
+                (GameActions::Block, KeyCode::Digit2),
+                (GameActions::Dodge, KeyCode::ShiftLeft),
+                (GameActions::Spell1, KeyCode::Digit3),
+                (GameActions::Spell2, KeyCode::Digit4),
+                (GameActions::Spell3, KeyCode::Digit5),
+                (GameActions::Inventory, KeyCode::KeyI),
+                (GameActions::Equip, KeyCode::KeyT),
+                (GameActions::Interact, KeyCode::KeyG),
+                (GameActions::Hotbar1, KeyCode::KeyZ),
+                (GameActions::Hotbar2, KeyCode::KeyX),
+                (GameActions::Hotbar3, KeyCode::KeyC),
+                (GameActions::Hotbar4, KeyCode::KeyV),
+                (GameActions::Hotbar5, KeyCode::KeyB),
                 ]),
             });
             let player_entity = player_commands.id();
@@ -72,6 +89,12 @@ pub fn setup_player(
             let entity = player_command.id();
             player_command
             .insert(NonMainPlayer)
+            .insert(SnapshotBuffer::new(Snapshot {
+                time: time.elapsed_seconds_f64(),
+                position: spawn_position,
+                direction: player_entity.data.direction,
+                moving: player_entity.data.moving,
+            }))
             .insert(AnimationController::new(GraphAnimations {
                 index_node: graph.index_node.clone(),
                 graph: graph.graph.clone(),
@@ -88,14 +111,15 @@ pub fn spawn_players(
     ga: Res<GameAssets>,
     mut player_data: ResMut<PlayerDataResource>,
     assets_gltf: Res<Assets<Gltf>>,
-    query: Query<&Player>,
+    entity_map: Res<NetworkEntityMap>,
     mut next_state: ResMut<NextState<HandleScenesState>>,
     graph: Res<GraphAnimations>,
+    time: Res<Time>,
 ) {
 
     for player_component in player_data.player_vec.iter_mut() {
        
-        let player_exists = query.iter().any(|p: &Player| p.data.entity_id == player_component.data.entity_id);
+        let player_exists = entity_map.contains(player_component.data.entity_id);
         
         if !player_exists {
             if let Some(player_entity_data) = entity_component::EntityComponent::filter_by_entity_id(player_component.data.entity_id).next() {
@@ -115,7 +139,8 @@ pub fn spawn_players(
                     main_player_bool,
                     &ga,
                     &assets_gltf,
-                    &graph
+                    &graph,
+                    &time,
                 );
 
                 next_state.set(HandleScenesState::Spawned);
@@ -129,13 +154,14 @@ pub fn spawn_new_players(
     player_data: ResMut<PlayerDataResource>,
     ga: Res<GameAssets>,
     assets_gltf: Res<Assets<Gltf>>,
-    query: Query<&Player>,
+    entity_map: Res<NetworkEntityMap>,
     next_state: ResMut<NextState<HandleScenesState>>,
     new_pl: Res<NewPlayer>,
     graph: Res<GraphAnimations>,
+    time: Res<Time>,
 ) {
 
     if new_pl.0 {
-        spawn_players(commands, ga, player_data, assets_gltf, query, next_state, graph);
+        spawn_players(commands, ga, player_data, assets_gltf, entity_map, next_state, graph, time);
     }
 }
\ No newline at end of file
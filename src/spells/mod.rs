@@ -0,0 +1,213 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    module_bindings::{EntityComponent, SpellCast, SpellDef, SpellEffect, SpellEntity},
+    network::{entity_map::NetworkEntityMap, uncb_receiver::{UncbEvent, UncbMessage}},
+    player::{player_bundle::PlayerEntity, HandleScenesState},
+};
+
+const CAST_BAR_WIDTH: f32 = 60.0;
+const CAST_BAR_HEIGHT: f32 = 6.0;
+// How far above a caster's origin the cast bar is drawn.
+const CAST_BAR_OFFSET: f32 = 1.2;
+const PROJECTILE_SIZE: f32 = 0.15;
+// How quickly spell visuals catch up with the replicated position, per second.
+const SPELL_FOLLOW_SPEED: f32 = 15.0;
+
+pub struct SpellsPlugin;
+impl Plugin for SpellsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SpellVisuals>()
+            .add_systems(Update, (
+                handle_spell_events,
+                update_cast_bars,
+                move_spell_visuals,
+            ).chain().run_if(in_state(HandleScenesState::Done)));
+    }
+}
+
+// Maps `SpellEntity::entity_id` and `SpellCast::cast_id` to what's drawn for them.
+#[derive(Resource, Default)]
+pub struct SpellVisuals {
+    pub entities: HashMap<u64, Entity>,
+    pub cast_bars: HashMap<u64, Entity>,
+}
+
+// Progress bar over a caster while a `SpellCast` row exists. The server clock
+// isn't shared with the client, so progress is timed locally from when the row arrived.
+#[derive(Component)]
+pub struct CastBar {
+    pub caster_id: u64,
+    pub timer: Timer,
+}
+
+#[derive(Component)]
+struct CastBarFill;
+
+// Placeholder effect for a projectile or area spell, following its `EntityComponent`.
+#[derive(Component)]
+pub struct SpellVisual {
+    pub entity_id: u64,
+}
+
+pub fn handle_spell_events(
+    mut commands: Commands,
+    mut event_reader: EventReader<UncbEvent>,
+    mut visuals: ResMut<SpellVisuals>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in event_reader.read() {
+        match &event.message {
+            UncbMessage::SpellCastInserted { data } => {
+                if visuals.cast_bars.contains_key(&data.cast_id) {
+                    continue;
+                }
+                let duration = data.finishes.saturating_sub(data.started) as f32 / 1_000_000.0;
+                let bar = commands
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                position_type: PositionType::Absolute,
+                                width: Val::Px(CAST_BAR_WIDTH),
+                                height: Val::Px(CAST_BAR_HEIGHT),
+                                ..default()
+                            },
+                            background_color: Color::srgba(0.0, 0.0, 0.0, 0.7).into(),
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        CastBar {
+                            caster_id: data.caster_id,
+                            timer: Timer::from_seconds(duration, TimerMode::Once),
+                        },
+                    ))
+                    .with_children(|bar| {
+                        bar.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(0.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                background_color: Color::srgb(0.3, 0.5, 1.0).into(),
+                                ..default()
+                            },
+                            CastBarFill,
+                        ));
+                    })
+                    .id();
+                visuals.cast_bars.insert(data.cast_id, bar);
+            }
+            UncbMessage::SpellCastRemoved { data } => {
+                if let Some(bar) = visuals.cast_bars.remove(&data.cast_id) {
+                    commands.entity(bar).despawn_recursive();
+                }
+            }
+            UncbMessage::SpellEntityInserted { data } => {
+                if visuals.entities.contains_key(&data.entity_id) {
+                    continue;
+                }
+                let radius = SpellDef::find_by_spell_id(data.spell_id).map(|def| def.radius).unwrap_or(0.5);
+                let (mesh, color) = match data.effect {
+                    SpellEffect::Projectile => (meshes.add(Sphere::new(PROJECTILE_SIZE)), Color::srgb(1.0, 0.5, 0.1)),
+                    _ => (meshes.add(Cylinder::new(radius, 0.05)), Color::srgba(0.4, 0.6, 1.0, 0.5)),
+                };
+                let material = materials.add(StandardMaterial {
+                    base_color: color,
+                    emissive: color.to_linear() * 2.0,
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                });
+                let position = EntityComponent::find_by_entity_id(data.entity_id)
+                    .map(|entity| entity.position)
+                    .unwrap_or(data.origin.clone());
+                let visual = commands
+                    .spawn((
+                        PbrBundle {
+                            mesh,
+                            material,
+                            transform: Transform::from_xyz(position.x, position.y, position.z),
+                            ..default()
+                        },
+                        SpellVisual { entity_id: data.entity_id },
+                    ))
+                    .id();
+                visuals.entities.insert(data.entity_id, visual);
+            }
+            UncbMessage::SpellEntityRemoved { data } => {
+                if let Some(visual) = visuals.entities.remove(&data.entity_id) {
+                    commands.entity(visual).despawn_recursive();
+                }
+            }
+            UncbMessage::SubscriptionApplied => {
+                // Drop whatever ended while we were disconnected.
+                visuals.cast_bars.retain(|cast_id, bar| {
+                    let exists = SpellCast::find_by_cast_id(*cast_id).is_some();
+                    if !exists {
+                        commands.entity(*bar).despawn_recursive();
+                    }
+                    exists
+                });
+                visuals.entities.retain(|entity_id, visual| {
+                    let exists = SpellEntity::find_by_entity_id(*entity_id).is_some();
+                    if !exists {
+                        commands.entity(*visual).despawn_recursive();
+                    }
+                    exists
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn update_cast_bars(
+    time: Res<Time>,
+    cam_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    entity_map: Res<NetworkEntityMap>,
+    caster_q: Query<&GlobalTransform, With<PlayerEntity>>,
+    mut bar_q: Query<(&mut CastBar, &mut Style, &mut Visibility, &Children), Without<CastBarFill>>,
+    mut fill_q: Query<&mut Style, With<CastBarFill>>,
+) {
+    let Ok((camera, cam_transform)) = cam_q.get_single() else {
+        return;
+    };
+
+    for (mut bar, mut style, mut visibility, children) in bar_q.iter_mut() {
+        bar.timer.tick(time.delta());
+
+        let caster = entity_map.get(bar.caster_id).and_then(|entity| caster_q.get(entity).ok());
+        let screen = caster.and_then(|transform| {
+            camera.world_to_viewport(cam_transform, transform.translation() + Vec3::Y * CAST_BAR_OFFSET)
+        });
+        let Some(screen) = screen else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        *visibility = Visibility::Visible;
+        style.left = Val::Px(screen.x - CAST_BAR_WIDTH / 2.0);
+        style.top = Val::Px(screen.y);
+        for child in children.iter() {
+            if let Ok(mut fill_style) = fill_q.get_mut(*child) {
+                fill_style.width = Val::Percent(bar.timer.fraction() * 100.0);
+            }
+        }
+    }
+}
+
+pub fn move_spell_visuals(
+    time: Res<Time>,
+    mut visual_q: Query<(&SpellVisual, &mut Transform)>,
+) {
+    let t = (SPELL_FOLLOW_SPEED * time.delta_seconds()).min(1.0);
+    for (visual, mut transform) in visual_q.iter_mut() {
+        if let Some(entity) = EntityComponent::find_by_entity_id(visual.entity_id) {
+            let target = Vec3::new(entity.position.x, entity.position.y, entity.position.z);
+            transform.translation = transform.translation.lerp(target, t);
+        }
+    }
+}
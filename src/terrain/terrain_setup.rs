@@ -32,7 +32,7 @@ pub fn setup_terrain(
     commands.insert_resource(TerrainEntititiesByName(scene_entities_by_name));
 
     println!("Terrain Spawned");
-    next_state.set(AssetLoadingState::Spawning);
+    next_state.set(AssetLoadingState::CharacterCreation);
     spawning_state.set(HandleScenesState::Spawning);
 }
 